@@ -23,6 +23,28 @@ pub enum Commands {
         verbose: bool,
     },
 
+    /// Check that the generated TypeScript files are up to date without rewriting them
+    Check {
+        /// Path to the configuration file
+        #[arg(short, long, default_value = "tauri-codegen.toml")]
+        config: PathBuf,
+
+        /// Enable verbose output
+        #[arg(short, long, default_value = "false")]
+        verbose: bool,
+    },
+
+    /// Regenerate TypeScript bindings whenever Rust sources or the config change
+    Watch {
+        /// Path to the configuration file
+        #[arg(short, long, default_value = "tauri-codegen.toml")]
+        config: PathBuf,
+
+        /// Enable verbose output
+        #[arg(short, long, default_value = "false")]
+        verbose: bool,
+    },
+
     /// Initialize a new configuration file
     Init {
         /// Path where to create the configuration file
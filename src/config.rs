@@ -1,5 +1,6 @@
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -10,6 +11,50 @@ pub struct Config {
     pub output: OutputConfig,
     #[serde(default)]
     pub naming: NamingConfig,
+    /// TypeScript types for Rust types the generator doesn't know, keyed by name or path
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub types: BTreeMap<String, TypeMapping>,
+}
+
+/// TypeScript side of a `[types]` entry
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum TypeMapping {
+    /// `"ulid::Ulid" = "string"`
+    Inline(String),
+    /// `"crate::money::Money" = { ts = "MoneyDto", import = "../money" }`
+    Imported {
+        /// TypeScript type
+        ts: String,
+        /// Module the type is imported from: a package, written as is into the generated
+        /// files, or a `./`/`../` path relative to the working directory like the other paths
+        #[serde(default)]
+        import: Option<String>,
+    },
+}
+
+impl TypeMapping {
+    /// The TypeScript type
+    pub fn ts(&self) -> &str {
+        match self {
+            TypeMapping::Inline(ts) | TypeMapping::Imported { ts, .. } => ts,
+        }
+    }
+
+    /// Module to import the TypeScript type from, if any
+    pub fn import(&self) -> Option<&str> {
+        match self {
+            TypeMapping::Inline(_) => None,
+            TypeMapping::Imported { import, .. } => import.as_deref(),
+        }
+    }
+
+    /// The import when it is a path to a module file rather than a package
+    pub fn import_path(&self) -> Option<&Path> {
+        self.import()
+            .map(Path::new)
+            .filter(|path| path.is_absolute() || path.starts_with(".") || path.starts_with(".."))
+    }
 }
 
 /// Input configuration - where to find Rust source files
@@ -26,6 +71,18 @@ pub struct InputConfig {
     /// Cargo manifest path for cargo expand (defaults to Cargo.toml in source_dir parent)
     #[serde(default)]
     pub cargo_manifest: Option<PathBuf>,
+    /// Only generate commands listed in a `tauri::generate_handler![...]` invocation
+    #[serde(default)]
+    pub only_registered_commands: bool,
+    /// Tauri plugins in this project: plugin name -> directory containing its commands
+    ///
+    /// Plugins built with `tauri::plugin::Builder::new("<name>")` are also detected automatically.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub plugins: BTreeMap<String, PathBuf>,
+    /// Extra argument types injected by the framework rather than passed from the frontend
+    /// (e.g. custom `CommandArg` extractors), by name or fully qualified path
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub injected_types: Vec<String>,
 }
 
 /// Output configuration - where to write generated TypeScript files
@@ -35,6 +92,42 @@ pub struct OutputConfig {
     pub types_file: PathBuf,
     /// Path for generated TypeScript commands file
     pub commands_file: PathBuf,
+    /// Path for generated TypeScript event helpers (defaults to `events.ts` next to the commands file)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub events_file: Option<PathBuf>,
+    /// Fixed-size arrays up to this length become TS tuples (`[T, T, T]`), longer ones become `T[]`
+    #[serde(default = "default_max_array_tuple_len")]
+    pub max_array_tuple_len: usize,
+    /// How commands returning `Result<T, E>` surface their errors
+    #[serde(default)]
+    pub error_handling: ErrorHandling,
+    /// Generate every serializable type, not only those reachable from commands
+    #[serde(default)]
+    pub export_all_serializable: bool,
+}
+
+/// How generated command functions report a `Result` error
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ErrorHandling {
+    /// Reject the promise with the error value (Tauri's default behaviour)
+    #[default]
+    Throw,
+    /// Resolve to `{ status: "ok", data } | { status: "error", error }`
+    Result,
+}
+
+impl OutputConfig {
+    /// Path for generated event helpers, falling back to `events.ts` next to the commands file
+    pub fn events_file(&self) -> PathBuf {
+        self.events_file
+            .clone()
+            .unwrap_or_else(|| self.commands_file.with_file_name("events.ts"))
+    }
+}
+
+fn default_max_array_tuple_len() -> usize {
+    16
 }
 
 /// Naming configuration - prefixes and suffixes for generated code
@@ -77,24 +170,6 @@ impl Config {
             );
         }
 
-        // Ensure output directories exist or can be created
-        // Note: create_dir_all is idempotent and handles race conditions
-        if let Some(parent) = self.output.types_file.parent() {
-            if !parent.as_os_str().is_empty() {
-                fs::create_dir_all(parent).with_context(|| {
-                    format!("Failed to create output directory: {}", parent.display())
-                })?;
-            }
-        }
-
-        if let Some(parent) = self.output.commands_file.parent() {
-            if !parent.as_os_str().is_empty() {
-                fs::create_dir_all(parent).with_context(|| {
-                    format!("Failed to create output directory: {}", parent.display())
-                })?;
-            }
-        }
-
         Ok(())
     }
 
@@ -106,12 +181,20 @@ impl Config {
                 exclude: vec!["tests".to_string(), "target".to_string()],
                 use_cargo_expand: false,
                 cargo_manifest: None,
+                only_registered_commands: false,
+                plugins: Default::default(),
+                injected_types: Vec::new(),
             },
             output: OutputConfig {
                 types_file: PathBuf::from("src/generated/types.ts"),
                 commands_file: PathBuf::from("src/generated/commands.ts"),
+                events_file: None,
+                max_array_tuple_len: default_max_array_tuple_len(),
+                error_handling: ErrorHandling::default(),
+                export_all_serializable: false,
             },
             naming: NamingConfig::default(),
+            types: Default::default(),
         }
     }
 
@@ -296,10 +379,17 @@ commands_file = "commands.ts"
                 exclude: vec!["tests".to_string()],
                 use_cargo_expand: false,
                 cargo_manifest: None,
+                only_registered_commands: false,
+                plugins: Default::default(),
+                injected_types: Vec::new(),
             },
             output: OutputConfig {
                 types_file: PathBuf::from("types.ts"),
                 commands_file: PathBuf::from("commands.ts"),
+                events_file: None,
+                max_array_tuple_len: 16,
+                error_handling: Default::default(),
+                export_all_serializable: false,
             },
             naming: NamingConfig {
                 type_prefix: "I".to_string(),
@@ -307,6 +397,7 @@ commands_file = "commands.ts"
                 function_prefix: "".to_string(),
                 function_suffix: "".to_string(),
             },
+            types: Default::default(),
         };
 
         config.save(&config_path).unwrap();
@@ -352,5 +443,128 @@ commands_file = "commands.ts"
 
         assert!(config.input.exclude.is_empty());
     }
-}
 
+    #[test]
+    fn test_error_handling_option() {
+        let config: Config = toml::from_str(
+            r#"
+[input]
+source_dir = "src"
+
+[output]
+types_file = "types.ts"
+commands_file = "commands.ts"
+error_handling = "result"
+"#,
+        )
+        .unwrap();
+        assert_eq!(config.output.error_handling, ErrorHandling::Result);
+
+        let config = Config::default_config();
+        assert_eq!(config.output.error_handling, ErrorHandling::Throw);
+    }
+
+    #[test]
+    fn test_export_all_serializable_option() {
+        let config: Config = toml::from_str(
+            r#"
+[input]
+source_dir = "src"
+
+[output]
+types_file = "types.ts"
+commands_file = "commands.ts"
+export_all_serializable = true
+"#,
+        )
+        .unwrap();
+        assert!(config.output.export_all_serializable);
+
+        let config = Config::default_config();
+        assert!(!config.output.export_all_serializable);
+    }
+
+    #[test]
+    fn test_injected_types_option() {
+        let config: Config = toml::from_str(
+            r#"
+[input]
+source_dir = "src"
+injected_types = ["DbConn", "crate::auth::Session"]
+
+[output]
+types_file = "types.ts"
+commands_file = "commands.ts"
+"#,
+        )
+        .unwrap();
+        assert_eq!(config.input.injected_types, vec!["DbConn", "crate::auth::Session"]);
+
+        let config = Config::default_config();
+        assert!(config.input.injected_types.is_empty());
+    }
+
+    #[test]
+    fn test_type_mappings() {
+        let config: Config = toml::from_str(
+            r#"
+[input]
+source_dir = "src"
+
+[output]
+types_file = "types.ts"
+commands_file = "commands.ts"
+
+[types]
+"ulid::Ulid" = "string"
+"crate::money::Money" = { ts = "MoneyDto", import = "../money" }
+"#,
+        )
+        .unwrap();
+
+        let ulid = &config.types["ulid::Ulid"];
+        assert_eq!(ulid.ts(), "string");
+        assert_eq!(ulid.import(), None);
+
+        let money = &config.types["crate::money::Money"];
+        assert_eq!(money.ts(), "MoneyDto");
+        assert_eq!(money.import(), Some("../money"));
+        assert_eq!(money.import_path(), Some(Path::new("../money")));
+
+        let package = TypeMapping::Imported {
+            ts: "Decimal".to_string(),
+            import: Some("@acme/decimal".to_string()),
+        };
+        assert_eq!(package.import_path(), None);
+    }
+
+    #[test]
+    fn test_load_keeps_relative_type_imports() {
+        let dir = tempdir().unwrap();
+        let source_dir = dir.path().join("src");
+        fs::create_dir_all(&source_dir).unwrap();
+
+        let config_content = format!(
+            r#"
+[input]
+source_dir = "{}"
+
+[output]
+types_file = "types.ts"
+commands_file = "commands.ts"
+
+[types]
+"crate::money::Money" = {{ ts = "MoneyDto", import = "./web/money" }}
+"decimal::Decimal" = {{ ts = "Decimal", import = "@acme/decimal" }}
+"#,
+            source_dir.display()
+        );
+        let config_path = dir.path().join("config.toml");
+        fs::write(&config_path, config_content).unwrap();
+
+        // Like source_dir and the output files, import paths are relative to the working directory
+        let config = Config::load(&config_path).unwrap();
+        assert_eq!(config.types["crate::money::Money"].import_path(), Some(Path::new("./web/money")));
+        assert_eq!(config.types["decimal::Decimal"].import(), Some("@acme/decimal"));
+    }
+}
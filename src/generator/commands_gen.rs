@@ -1,9 +1,13 @@
+use crate::config::ErrorHandling;
 use crate::models::{CommandArg, RustType, TauriCommand};
-use crate::utils::to_camel_case;
-use std::collections::HashSet;
+use crate::utils::{apply_field_rename_all, to_camel_case};
+use std::collections::{BTreeMap, HashSet};
 use std::path::Path;
 
-use super::{type_mapper::rust_to_typescript, GeneratorContext};
+use super::{
+    collect_mapped_imports, jsdoc::render_jsdoc, render_mapped_imports, type_mapper::rust_to_typescript,
+    GeneratorContext, GENERATED_HEADER,
+};
 
 /// Generate TypeScript commands file content
 pub fn generate_commands_file(
@@ -15,11 +19,15 @@ pub fn generate_commands_file(
     let mut output = String::new();
 
     // Add header comment
-    output.push_str("// This file was auto-generated by tauri-ts-generator\n");
+    output.push_str(GENERATED_HEADER);
     output.push_str("// Do not edit this file manually\n\n");
 
-    // Add Tauri import
-    output.push_str("import { invoke } from \"@tauri-apps/api/core\";\n");
+    // Add Tauri import (Channel is a class the caller instantiates, so it's a value import)
+    if commands.iter().flat_map(|cmd| &cmd.args).any(|arg| uses_channel(&arg.ty)) {
+        output.push_str("import { Channel, invoke } from \"@tauri-apps/api/core\";\n");
+    } else {
+        output.push_str("import { invoke } from \"@tauri-apps/api/core\";\n");
+    }
 
     // Collect all custom types used in commands
     let used_types = collect_used_types(commands, ctx);
@@ -36,14 +44,52 @@ pub fn generate_commands_file(
         ));
     }
 
+    // Import types mapped in the [types] config
+    let mut mapped_imports = BTreeMap::new();
+    for cmd in commands {
+        for arg in &cmd.args {
+            collect_mapped_imports(&arg.ty, ctx, &mut mapped_imports);
+        }
+        if let Some(ret_type) = &cmd.return_type {
+            collect_mapped_imports(ret_type, ctx, &mut mapped_imports);
+        }
+    }
+    output.push_str(&render_mapped_imports(&mapped_imports));
+
     output.push('\n');
 
+    if ctx.error_handling == ErrorHandling::Result
+        && commands.iter().any(|cmd| command_error_type(cmd, ctx).is_some())
+    {
+        output.push_str("export type CommandResult<T, E> =\n");
+        output.push_str("  | { status: \"ok\"; data: T }\n");
+        output.push_str("  | { status: \"error\"; error: E };\n\n");
+    }
+
     // Generate function for each command
-    for cmd in commands {
+    for cmd in commands.iter().filter(|cmd| cmd.plugin.is_none()) {
         output.push_str(&generate_command_function(cmd, ctx));
         output.push('\n');
     }
 
+    // Plugin commands are grouped into one object per plugin
+    let mut plugins: BTreeMap<&str, Vec<&TauriCommand>> = BTreeMap::new();
+    for cmd in commands {
+        if let Some(plugin) = &cmd.plugin {
+            plugins.entry(plugin).or_default().push(cmd);
+        }
+    }
+    for (plugin, plugin_commands) in plugins {
+        output.push_str(&generate_plugin_namespace(plugin, &plugin_commands, ctx));
+        output.push('\n');
+    }
+
+    let error_map = generate_command_error_map(commands, ctx);
+    if !error_map.is_empty() {
+        output.push_str(&error_map);
+        output.push('\n');
+    }
+
     output
 }
 
@@ -66,16 +112,39 @@ fn collect_used_types(commands: &[TauriCommand], ctx: &GeneratorContext) -> Hash
     types
 }
 
+/// Whether a type contains a `Channel<T>`
+fn uses_channel(ty: &RustType) -> bool {
+    match ty {
+        RustType::Channel(_) => true,
+        RustType::Vec(inner) | RustType::Option(inner) | RustType::Array { elem: inner, .. } => uses_channel(inner),
+        RustType::Tuple(types) => types.iter().any(uses_channel),
+        _ => false,
+    }
+}
+
 /// Recursively collect custom type names from a RustType
 fn collect_types_from_rust_type(ty: &RustType, ctx: &GeneratorContext, types: &mut HashSet<String>) {
     match ty {
         RustType::Custom(name) if ctx.is_custom_type(name) => {
             types.insert(ctx.format_type_name(name));
         }
+        RustType::CustomGeneric { name, args } => {
+            if ctx.is_custom_type(name) {
+                types.insert(ctx.format_type_name(name));
+            }
+            for arg in args {
+                collect_types_from_rust_type(arg, ctx, types);
+            }
+        }
         RustType::Vec(inner) => collect_types_from_rust_type(inner, ctx, types),
+        RustType::Array { elem, .. } => collect_types_from_rust_type(elem, ctx, types),
         RustType::Option(inner) => collect_types_from_rust_type(inner, ctx, types),
-        RustType::Result(ok) => {
+        RustType::Channel(inner) => collect_types_from_rust_type(inner, ctx, types),
+        RustType::Result { ok, err } => {
             collect_types_from_rust_type(ok, ctx, types);
+            if let Some(err) = err {
+                collect_types_from_rust_type(err, ctx, types);
+            }
         }
         RustType::HashMap { key, value } => {
             collect_types_from_rust_type(key, ctx, types);
@@ -92,36 +161,117 @@ fn collect_types_from_rust_type(ty: &RustType, ctx: &GeneratorContext, types: &m
 
 /// Generate a TypeScript function for a Tauri command
 fn generate_command_function(cmd: &TauriCommand, ctx: &GeneratorContext) -> String {
+    generate_command(cmd, "export async function ", ctx)
+}
+
+/// Generate an `export const <plugin> = { ... }` object holding a plugin's commands
+fn generate_plugin_namespace(plugin: &str, commands: &[&TauriCommand], ctx: &GeneratorContext) -> String {
+    let mut output = format!(
+        "/** Commands of the `{}` plugin */\nexport const {} = {{\n",
+        plugin,
+        plugin_namespace(plugin)
+    );
+
+    let methods: Vec<_> = commands
+        .iter()
+        .map(|cmd| {
+            let method = generate_command(cmd, "async ", ctx);
+            let indented: String = method
+                .lines()
+                .map(|line| if line.is_empty() { "\n".to_string() } else { format!("  {}\n", line) })
+                .collect();
+            // Object members are separated by commas
+            format!("{},\n", indented.trim_end())
+        })
+        .collect();
+    output.push_str(&methods.join("\n"));
+
+    output.push_str("};\n");
+    output
+}
+
+/// TypeScript identifier for a plugin's namespace object
+fn plugin_namespace(plugin: &str) -> String {
+    to_camel_case(&plugin.replace('-', "_"))
+}
+
+/// Generate a command function or method, introduced by `declaration`
+fn generate_command(cmd: &TauriCommand, declaration: &str, ctx: &GeneratorContext) -> String {
     let mut output = String::new();
 
-    // Function name in camelCase
-    let fn_name = to_camel_case(&cmd.name);
-    let fn_name = ctx.format_function_name(&fn_name);
+    let fn_name = function_name(cmd, ctx);
 
-    // Generate parameter list
-    let params = generate_params(&cmd.args, ctx);
+    // Generate parameter list; a raw request body replaces the JSON arguments
+    let params = if cmd.raw_body {
+        "body: ArrayBuffer | Uint8Array, headers?: Record<string, string>".to_string()
+    } else {
+        generate_params(cmd, ctx)
+    };
 
     // Generate return type
     let return_type = generate_return_type(&cmd.return_type, ctx);
 
-    // Generate function signature
-    output.push_str(&format!(
-        "export async function {}({}): Promise<{}> {{\n",
-        fn_name, params, return_type
-    ));
+    // Commands returning Result either reject with the error or, in result mode, resolve to it
+    let error_type = command_error_type(cmd, ctx);
+    let returns_result = error_type.is_some() && ctx.error_handling == ErrorHandling::Result;
 
-    // Generate invoke call
-    if cmd.args.is_empty() {
-        output.push_str(&format!(
-            "  return invoke<{}>(\"{}\");\n",
-            return_type, cmd.name
-        ));
+    // Doc comment, with argument docs as @param tags
+    let mut tags: Vec<_> = cmd
+        .args
+        .iter()
+        .filter(|arg| !arg.docs.is_empty())
+        .map(|arg| format!("@param {} {}", param_name(cmd, arg), arg.docs.lines.join(" ")))
+        .collect();
+    if let Some(error_type) = error_type.as_ref().filter(|_| !returns_result) {
+        tags.push(format!("@throws {{{}}}", error_type));
+    }
+    output.push_str(&render_jsdoc(&cmd.docs, &tags, ""));
+
+    // Generate invoke call; plugin commands are addressed as `plugin:<name>|<command>`
+    let invoke_name = match &cmd.plugin {
+        Some(plugin) => format!("plugin:{}|{}", plugin, cmd.name),
+        None => cmd.name.clone(),
+    };
+    let invoke_call = if cmd.raw_body {
+        format!(
+            "invoke<{}>(\"{}\", body, headers ? {{ headers }} : undefined)",
+            return_type, invoke_name
+        )
+    } else if cmd.args.is_empty() {
+        format!("invoke<{}>(\"{}\")", return_type, invoke_name)
     } else {
-        let args_obj = generate_args_object(&cmd.args, cmd.rename_all.as_deref());
-        output.push_str(&format!(
-            "  return invoke<{}>(\"{}\", {{ {} }});\n",
-            return_type, cmd.name, args_obj
-        ));
+        let args_obj = generate_args_object(cmd);
+        format!(
+            "invoke<{}>(\"{}\", {{ {} }})",
+            return_type, invoke_name, args_obj
+        )
+    };
+
+    match error_type.filter(|_| returns_result) {
+        Some(error_type) => {
+            output.push_str(&format!(
+                "{}{}({}): Promise<CommandResult<{}, {}>> {{\n",
+                declaration, fn_name, params, return_type, error_type
+            ));
+            output.push_str("  try {\n");
+            output.push_str(&format!(
+                "    return {{ status: \"ok\", data: await {} }};\n",
+                invoke_call
+            ));
+            output.push_str("  } catch (e) {\n");
+            output.push_str(&format!(
+                "    return {{ status: \"error\", error: e as {} }};\n",
+                error_type
+            ));
+            output.push_str("  }\n");
+        }
+        None => {
+            output.push_str(&format!(
+                "{}{}({}): Promise<{}> {{\n",
+                declaration, fn_name, params, return_type
+            ));
+            output.push_str(&format!("  return {};\n", invoke_call));
+        }
     }
 
     output.push_str("}\n");
@@ -129,11 +279,60 @@ fn generate_command_function(cmd: &TauriCommand, ctx: &GeneratorContext) -> Stri
     output
 }
 
+/// TypeScript error type of a command returning `Result<T, E>`
+fn command_error_type(cmd: &TauriCommand, ctx: &GeneratorContext) -> Option<String> {
+    match &cmd.return_type {
+        Some(RustType::Result { err, .. }) => Some(match err {
+            Some(err) => rust_to_typescript(err, ctx),
+            None => "unknown".to_string(),
+        }),
+        _ => None,
+    }
+}
+
+/// Generate the `CommandError` map from function name to error type
+fn generate_command_error_map(commands: &[TauriCommand], ctx: &GeneratorContext) -> String {
+    let entries: Vec<_> = commands
+        .iter()
+        .filter_map(|cmd| {
+            let error_type = command_error_type(cmd, ctx)?;
+            let fn_name = function_name(cmd, ctx);
+            Some(match &cmd.plugin {
+                Some(plugin) => format!("  \"{}.{}\": {};\n", plugin_namespace(plugin), fn_name, error_type),
+                None => format!("  {}: {};\n", fn_name, error_type),
+            })
+        })
+        .collect();
+
+    if entries.is_empty() {
+        return String::new();
+    }
+
+    format!(
+        "/** Error type of each command that returns a `Result` */\nexport type CommandError = {{\n{}}};\n",
+        entries.concat()
+    )
+}
+
+/// TypeScript function name: `#[ts(rename)]` as is, otherwise the formatted camelCase name
+fn function_name(cmd: &TauriCommand, ctx: &GeneratorContext) -> String {
+    match &cmd.ts_name {
+        Some(ts_name) => ts_name.clone(),
+        None => ctx.format_function_name(&to_camel_case(&cmd.name)),
+    }
+}
+
+/// TypeScript parameter name, camelCase unless `#[ts(rename_all)]` says otherwise
+fn param_name(cmd: &TauriCommand, arg: &CommandArg) -> String {
+    apply_field_rename_all(&arg.name, &cmd.ts_rename_all).unwrap_or_else(|| to_camel_case(&arg.name))
+}
+
 /// Generate TypeScript parameter list
-fn generate_params(args: &[CommandArg], ctx: &GeneratorContext) -> String {
-    args.iter()
+fn generate_params(cmd: &TauriCommand, ctx: &GeneratorContext) -> String {
+    cmd.args
+        .iter()
         .map(|arg| {
-            let param_name = to_camel_case(&arg.name);
+            let param_name = param_name(cmd, arg);
             let param_type = rust_to_typescript(&arg.ty, ctx);
             format!("{}: {}", param_name, param_type)
         })
@@ -142,8 +341,11 @@ fn generate_params(args: &[CommandArg], ctx: &GeneratorContext) -> String {
 }
 
 /// Generate TypeScript return type
+///
+/// A returned `Result` resolves to its Ok value; the error is handled separately.
 fn generate_return_type(return_type: &Option<RustType>, ctx: &GeneratorContext) -> String {
     match return_type {
+        Some(RustType::Result { ok, .. }) => rust_to_typescript(ok, ctx),
         Some(ty) => rust_to_typescript(ty, ctx),
         None => "void".to_string(),
     }
@@ -153,26 +355,25 @@ fn generate_return_type(return_type: &Option<RustType>, ctx: &GeneratorContext)
 /// 
 /// By default, Tauri serializes command arguments to camelCase.
 /// If `rename_all = "snake_case"` is specified, arguments stay as snake_case.
-fn generate_args_object(args: &[CommandArg], rename_all: Option<&str>) -> String {
-    let use_snake_case = rename_all == Some("snake_case");
+fn generate_args_object(cmd: &TauriCommand) -> String {
+    let use_snake_case = cmd.rename_all.as_deref() == Some("snake_case");
     
-    args.iter()
+    cmd.args
+        .iter()
         .map(|arg| {
-            let param_name = to_camel_case(&arg.name);
-            
-            if use_snake_case {
-                // With rename_all = "snake_case", Tauri expects snake_case keys
-                if arg.name == param_name {
-                    // Already the same (e.g., single word like "id")
-                    param_name
-                } else {
-                    // Map camelCase param to snake_case key
-                    format!("{}: {}", arg.name, param_name)
-                }
+            let param_name = param_name(cmd, arg);
+            // With rename_all = "snake_case", Tauri expects snake_case keys, camelCase otherwise
+            let key = if use_snake_case {
+                arg.name.clone()
             } else {
-                // Default: Tauri expects camelCase keys
+                to_camel_case(&arg.name)
+            };
+
+            if key == param_name {
                 // Use shorthand since param name matches key name
                 param_name
+            } else {
+                format!("{}: {}", key, param_name)
             }
         })
         .collect::<Vec<_>>()
@@ -180,7 +381,7 @@ fn generate_args_object(args: &[CommandArg], rename_all: Option<&str>) -> String
 }
 
 /// Calculate relative import path from commands file to types file
-fn calculate_relative_import(types_file: &Path, commands_file: &Path) -> String {
+pub(crate) fn calculate_relative_import(types_file: &Path, commands_file: &Path) -> String {
     // Get the directory of the commands file
     let commands_dir = commands_file.parent().unwrap_or(Path::new("."));
     let types_dir = types_file.parent().unwrap_or(Path::new("."));
@@ -267,10 +468,16 @@ mod tests {
             args: vec![CommandArg {
                 name: "id".to_string(),
                 ty: RustType::Primitive("i32".to_string()),
+                docs: Default::default(),
             }],
             return_type: Some(RustType::Custom("User".to_string())),
             source_file: test_path(),
             rename_all: None,
+            docs: Default::default(),
+            plugin: None,
+            raw_body: false,
+            ts_name: None,
+            ts_rename_all: None,
         };
 
         let mut ctx = default_ctx();
@@ -292,6 +499,11 @@ mod tests {
             return_type: Some(RustType::Vec(Box::new(RustType::Custom("Item".to_string())))),
             source_file: test_path(),
             rename_all: None,
+            docs: Default::default(),
+            plugin: None,
+            raw_body: false,
+            ts_name: None,
+            ts_rename_all: None,
         };
 
         let ctx = ctx_with_type("Item");
@@ -312,19 +524,27 @@ mod tests {
                 CommandArg {
                     name: "name".to_string(),
                     ty: RustType::Primitive("String".to_string()),
+                    docs: Default::default(),
                 },
                 CommandArg {
                     name: "age".to_string(),
                     ty: RustType::Primitive("i32".to_string()),
+                    docs: Default::default(),
                 },
                 CommandArg {
                     name: "email".to_string(),
                     ty: RustType::Option(Box::new(RustType::Primitive("String".to_string()))),
+                    docs: Default::default(),
                 },
             ],
             return_type: Some(RustType::Custom("User".to_string())),
             source_file: test_path(),
             rename_all: None,
+            docs: Default::default(),
+            plugin: None,
+            raw_body: false,
+            ts_name: None,
+            ts_rename_all: None,
         };
 
         let ctx = ctx_with_type("User");
@@ -342,10 +562,16 @@ mod tests {
             args: vec![CommandArg {
                 name: "id".to_string(),
                 ty: RustType::Primitive("i32".to_string()),
+                docs: Default::default(),
             }],
             return_type: None,
             source_file: test_path(),
             rename_all: None,
+            docs: Default::default(),
+            plugin: None,
+            raw_body: false,
+            ts_name: None,
+            ts_rename_all: None,
         };
 
         let ctx = default_ctx();
@@ -363,6 +589,11 @@ mod tests {
             return_type: None,
             source_file: test_path(),
             rename_all: None,
+            docs: Default::default(),
+            plugin: None,
+            raw_body: false,
+            ts_name: None,
+            ts_rename_all: None,
         };
 
         let ctx = default_ctx();
@@ -379,10 +610,16 @@ mod tests {
             args: vec![CommandArg {
                 name: "user_id".to_string(),
                 ty: RustType::Primitive("i32".to_string()),
+                docs: Default::default(),
             }],
             return_type: None,
             source_file: test_path(),
             rename_all: None,
+            docs: Default::default(),
+            plugin: None,
+            raw_body: false,
+            ts_name: None,
+            ts_rename_all: None,
         };
 
         let ctx = default_ctx();
@@ -404,10 +641,16 @@ mod tests {
             args: vec![CommandArg {
                 name: "user_id".to_string(),
                 ty: RustType::Primitive("i32".to_string()),
+                docs: Default::default(),
             }],
             return_type: None,
             source_file: test_path(),
             rename_all: Some("snake_case".to_string()),
+            docs: Default::default(),
+            plugin: None,
+            raw_body: false,
+            ts_name: None,
+            ts_rename_all: None,
         };
 
         let ctx = default_ctx();
@@ -428,16 +671,27 @@ mod tests {
                 return_type: Some(RustType::Custom("User".to_string())),
                 source_file: test_path(),
                 rename_all: None,
+                docs: Default::default(),
+                plugin: None,
+                raw_body: false,
+                ts_name: None,
+                ts_rename_all: None,
             },
             TauriCommand {
                 name: "create".to_string(),
                 args: vec![CommandArg {
                     name: "req".to_string(),
                     ty: RustType::Custom("CreateRequest".to_string()),
+                    docs: Default::default(),
                 }],
                 return_type: Some(RustType::Custom("User".to_string())),
                 source_file: test_path(),
                 rename_all: None,
+                docs: Default::default(),
+                plugin: None,
+                raw_body: false,
+                ts_name: None,
+                ts_rename_all: None,
             },
         ];
 
@@ -462,6 +716,11 @@ mod tests {
             ))))),
             source_file: test_path(),
             rename_all: None,
+            docs: Default::default(),
+            plugin: None,
+            raw_body: false,
+            ts_name: None,
+            ts_rename_all: None,
         }];
 
         let ctx = ctx_with_type("User");
@@ -470,6 +729,38 @@ mod tests {
         assert!(types.contains("User"));
     }
 
+    #[test]
+    fn test_generic_return_type_and_imports() {
+        let commands = vec![TauriCommand {
+            name: "list_users".to_string(),
+            args: vec![],
+            return_type: Some(RustType::Result {
+                ok: Box::new(RustType::CustomGeneric {
+                    name: "Page".to_string(),
+                    args: vec![RustType::Custom("User".to_string())],
+                }),
+                err: Some(Box::new(RustType::Primitive("String".to_string()))),
+            }),
+            source_file: test_path(),
+            rename_all: None,
+            docs: Default::default(),
+            plugin: None,
+            raw_body: false,
+            ts_name: None,
+            ts_rename_all: None,
+        }];
+
+        let mut ctx = ctx_with_type("Page");
+        ctx.register_type("User");
+
+        let output = generate_command_function(&commands[0], &ctx);
+        assert!(output.contains("Promise<Page<User>>"));
+
+        let types = collect_used_types(&commands, &ctx);
+        assert!(types.contains("Page"));
+        assert!(types.contains("User"));
+    }
+
     #[test]
     fn test_relative_import_path_same_dir() {
         let types_file = Path::new("src/generated/types.ts");
@@ -487,6 +778,11 @@ mod tests {
             return_type: None,
             source_file: test_path(),
             rename_all: None,
+            docs: Default::default(),
+            plugin: None,
+            raw_body: false,
+            ts_name: None,
+            ts_rename_all: None,
         };
 
         let ctx = GeneratorContext::new(NamingConfig {
@@ -508,6 +804,11 @@ mod tests {
             return_type: None,
             source_file: test_path(),
             rename_all: None,
+            docs: Default::default(),
+            plugin: None,
+            raw_body: false,
+            ts_name: None,
+            ts_rename_all: None,
         };
 
         let ctx = GeneratorContext::new(NamingConfig {
@@ -543,6 +844,11 @@ mod tests {
             return_type: Some(RustType::Custom("User".to_string())),
             source_file: test_path(),
             rename_all: None,
+            docs: Default::default(),
+            plugin: None,
+            raw_body: false,
+            ts_name: None,
+            ts_rename_all: None,
         }];
 
         let types_path = Path::new("src/generated/types.ts");
@@ -559,11 +865,17 @@ mod tests {
         let cmd = TauriCommand {
             name: "search".to_string(),
             args: vec![],
-            return_type: Some(RustType::Result(Box::new(RustType::Vec(Box::new(
-                RustType::Custom("User".to_string()),
-            ))))),
+            return_type: Some(RustType::Result {
+                ok: Box::new(RustType::Vec(Box::new(RustType::Custom("User".to_string())))),
+                err: Some(Box::new(RustType::Primitive("String".to_string()))),
+            }),
             source_file: test_path(),
             rename_all: None,
+            docs: Default::default(),
+            plugin: None,
+            raw_body: false,
+            ts_name: None,
+            ts_rename_all: None,
         };
 
         let ctx = ctx_with_type("User");
@@ -572,6 +884,59 @@ mod tests {
         assert!(output.contains("Promise<User[]>"));
     }
 
+    #[test]
+    fn test_channel_arg_imports_channel() {
+        let commands = vec![TauriCommand {
+            name: "download".to_string(),
+            args: vec![CommandArg {
+                name: "on_event".to_string(),
+                ty: RustType::Channel(Box::new(RustType::Custom("ProgressEvent".to_string()))),
+                docs: Default::default(),
+            }],
+            return_type: None,
+            source_file: test_path(),
+            rename_all: None,
+            docs: Default::default(),
+            plugin: None,
+            raw_body: false,
+            ts_name: None,
+            ts_rename_all: None,
+        }];
+
+        let types_path = Path::new("types.ts");
+        let commands_path = Path::new("commands.ts");
+        let ctx = ctx_with_type("ProgressEvent");
+
+        let output = generate_commands_file(&commands, types_path, commands_path, &ctx);
+
+        assert!(output.contains("import { Channel, invoke } from \"@tauri-apps/api/core\";"));
+        assert!(output.contains("import type { ProgressEvent } from \"./types\";"));
+        assert!(output.contains("download(onEvent: Channel<ProgressEvent>): Promise<void>"));
+    }
+
+    #[test]
+    fn test_raw_ipc_command() {
+        let cmd = TauriCommand {
+            name: "upload".to_string(),
+            args: vec![],
+            return_type: Some(RustType::Binary),
+            source_file: test_path(),
+            rename_all: None,
+            docs: Default::default(),
+            plugin: None,
+            raw_body: true,
+            ts_name: None,
+            ts_rename_all: None,
+        };
+
+        let output = generate_command_function(&cmd, &default_ctx());
+
+        assert!(output.contains(
+            "export async function upload(body: ArrayBuffer | Uint8Array, headers?: Record<string, string>): Promise<ArrayBuffer> {"
+        ));
+        assert!(output.contains("return invoke<ArrayBuffer>(\"upload\", body, headers ? { headers } : undefined);"));
+    }
+
     #[test]
     fn test_imports_are_sorted() {
         let commands = vec![TauriCommand {
@@ -580,19 +945,27 @@ mod tests {
                 CommandArg {
                     name: "a".to_string(),
                     ty: RustType::Custom("BType".to_string()),
+                    docs: Default::default(),
                 },
                 CommandArg {
                     name: "b".to_string(),
                     ty: RustType::Custom("AType".to_string()),
+                    docs: Default::default(),
                 },
                 CommandArg {
                     name: "c".to_string(),
                     ty: RustType::Custom("CType".to_string()),
+                    docs: Default::default(),
                 },
             ],
             return_type: None,
             source_file: test_path(),
             rename_all: None,
+            docs: Default::default(),
+            plugin: None,
+            raw_body: false,
+            ts_name: None,
+            ts_rename_all: None,
         }];
 
         let types_path = Path::new("types.ts");
@@ -607,4 +980,172 @@ mod tests {
         // Should be AType, BType, CType
         assert!(output.contains("import type { AType, BType, CType }"));
     }
+
+    #[test]
+    fn test_generate_command_jsdoc() {
+        let cmd = TauriCommand {
+            name: "get_user".to_string(),
+            args: vec![CommandArg {
+                name: "user_id".to_string(),
+                ty: RustType::Primitive("i32".to_string()),
+                docs: crate::models::Docs {
+                    lines: vec!["The user's ID".to_string()],
+                    deprecated: None,
+                },
+            }],
+            return_type: None,
+            source_file: test_path(),
+            rename_all: None,
+            docs: crate::models::Docs {
+                lines: vec!["Fetch a user.".to_string()],
+                deprecated: Some("use getUserV2".to_string()),
+            },
+            plugin: None,
+            raw_body: false,
+            ts_name: None,
+            ts_rename_all: None,
+        };
+
+        let output = generate_command_function(&cmd, &default_ctx());
+
+        assert!(output.starts_with(
+            "/**\n * Fetch a user.\n *\n * @param userId The user's ID\n * @deprecated use getUserV2\n */\nexport async function getUser("
+        ));
+    }
+
+    fn fallible_command() -> TauriCommand {
+        TauriCommand {
+            name: "get_user".to_string(),
+            args: vec![CommandArg {
+                name: "id".to_string(),
+                ty: RustType::Primitive("i32".to_string()),
+                docs: Default::default(),
+            }],
+            return_type: Some(RustType::Result {
+                ok: Box::new(RustType::Custom("User".to_string())),
+                err: Some(Box::new(RustType::Custom("AppError".to_string()))),
+            }),
+            source_file: test_path(),
+            rename_all: None,
+            docs: Default::default(),
+            plugin: None,
+            raw_body: false,
+            ts_name: None,
+            ts_rename_all: None,
+        }
+    }
+
+    #[test]
+    fn test_result_error_type_is_exposed() {
+        let mut ctx = ctx_with_type("User");
+        ctx.register_type("AppError");
+
+        let output = generate_commands_file(
+            &[fallible_command()],
+            Path::new("types.ts"),
+            Path::new("commands.ts"),
+            &ctx,
+        );
+
+        assert!(output.contains("import type { AppError, User } from \"./types\";"));
+        assert!(output.contains("/**\n * @throws {AppError}\n */\nexport async function getUser(id: number): Promise<User> {"));
+        assert!(output.contains("export type CommandError = {\n  getUser: AppError;\n};"));
+        assert!(!output.contains("CommandResult"));
+    }
+
+    #[test]
+    fn test_result_error_handling_mode() {
+        let mut ctx = ctx_with_type("User");
+        ctx.register_type("AppError");
+        ctx.error_handling = ErrorHandling::Result;
+
+        let commands = vec![
+            fallible_command(),
+            TauriCommand {
+                name: "ping".to_string(),
+                args: vec![],
+                return_type: None,
+                source_file: test_path(),
+                rename_all: None,
+                docs: Default::default(),
+                plugin: None,
+                raw_body: false,
+                ts_name: None,
+                ts_rename_all: None,
+            },
+        ];
+        let output =
+            generate_commands_file(&commands, Path::new("types.ts"), Path::new("commands.ts"), &ctx);
+
+        assert!(output.contains(
+            "export type CommandResult<T, E> =\n  | { status: \"ok\"; data: T }\n  | { status: \"error\"; error: E };"
+        ));
+        assert!(output.contains(
+            "export async function getUser(id: number): Promise<CommandResult<User, AppError>> {\n  try {\n    return { status: \"ok\", data: await invoke<User>(\"get_user\", { id }) };\n  } catch (e) {\n    return { status: \"error\", error: e as AppError };\n  }\n}"
+        ));
+        assert!(!output.contains("@throws"));
+        // Infallible commands keep their plain signature
+        assert!(output.contains("export async function ping(): Promise<void> {\n  return invoke<void>(\"ping\");\n}"));
+    }
+
+    #[test]
+    fn test_result_without_error_type_throws_unknown() {
+        let mut cmd = fallible_command();
+        cmd.return_type = Some(RustType::Result {
+            ok: Box::new(RustType::Custom("User".to_string())),
+            err: None,
+        });
+
+        let output = generate_command_function(&cmd, &ctx_with_type("User"));
+        assert!(output.contains("@throws {unknown}"));
+    }
+
+    #[test]
+    fn test_plugin_commands_are_namespaced() {
+        let mut get_item = fallible_command();
+        get_item.name = "get_item".to_string();
+        get_item.plugin = Some("secure-storage".to_string());
+        get_item.docs.lines = vec!["Read a value".to_string()];
+
+        let mut clear = get_item.clone();
+        clear.name = "clear".to_string();
+        clear.args.clear();
+        clear.return_type = None;
+        clear.docs = Default::default();
+
+        let mut ctx = ctx_with_type("User");
+        ctx.register_type("AppError");
+        let output = generate_commands_file(
+            &[get_item, clear],
+            Path::new("types.ts"),
+            Path::new("commands.ts"),
+            &ctx,
+        );
+
+        assert!(output.contains(
+            "/** Commands of the `secure-storage` plugin */\nexport const secureStorage = {\n  /**\n   * Read a value\n   *\n   * @throws {AppError}\n   */\n  async getItem(id: number): Promise<User> {\n    return invoke<User>(\"plugin:secure-storage|get_item\", { id });\n  },\n\n  async clear(): Promise<void> {\n    return invoke<void>(\"plugin:secure-storage|clear\");\n  },\n};\n"
+        ), "Output:\n{}", output);
+        assert!(output.contains("  \"secureStorage.getItem\": AppError;"));
+        assert!(!output.contains("export async function"));
+    }
+
+    #[test]
+    fn test_ts_rename_and_rename_all() {
+        let mut cmd = fallible_command();
+        cmd.args[0].name = "user_id".to_string();
+        cmd.ts_name = Some("fetchUser".to_string());
+        cmd.ts_rename_all = Some("snake_case".to_string());
+
+        let mut ctx = ctx_with_type("User");
+        ctx.register_type("AppError");
+        ctx.naming.function_prefix = "api".to_string();
+
+        let output = generate_command_function(&cmd, &ctx);
+        // The renamed function is used as is, parameters follow ts rename_all, keys stay camelCase for Tauri
+        assert!(output.contains("export async function fetchUser(user_id: number): Promise<User>"), "{}", output);
+        assert!(output.contains("invoke<User>(\"get_user\", { userId: user_id })"));
+
+        let error_map = generate_command_error_map(&[cmd], &ctx);
+        assert!(error_map.contains("  fetchUser: AppError;"));
+    }
 }
@@ -0,0 +1,206 @@
+use crate::models::{RustEnum, RustStruct};
+use crate::utils::to_pascal_case;
+use std::path::Path;
+
+use super::{commands_gen::calculate_relative_import, GeneratorContext, GENERATED_HEADER};
+
+/// An event payload type, marked with `#[ts(event = "...")]`
+struct EventPayload<'a> {
+    event: &'a str,
+    type_name: &'a str,
+    generics: usize,
+}
+
+/// Generate TypeScript event helpers file content
+///
+/// Returns `None` when no type is marked as an event payload.
+pub fn generate_events_file(
+    structs: &[RustStruct],
+    enums: &[RustEnum],
+    types_file_path: &Path,
+    events_file_path: &Path,
+    ctx: &GeneratorContext,
+) -> Option<String> {
+    let mut events: Vec<EventPayload> = structs
+        .iter()
+        .filter_map(|s| {
+            s.event.as_deref().map(|event| EventPayload {
+                event,
+                type_name: &s.name,
+                generics: s.generics.len(),
+            })
+        })
+        .chain(enums.iter().filter_map(|e| {
+            e.event.as_deref().map(|event| EventPayload {
+                event,
+                type_name: &e.name,
+                generics: e.generics.len(),
+            })
+        }))
+        .collect();
+
+    if events.is_empty() {
+        return None;
+    }
+    events.sort_by(|a, b| a.event.cmp(b.event));
+
+    let mut output = String::new();
+
+    // Add header comment
+    output.push_str(GENERATED_HEADER);
+    output.push_str("// Do not edit this file manually\n\n");
+
+    output.push_str("import { emit, listen } from \"@tauri-apps/api/event\";\n");
+    output.push_str("import type { EventCallback, UnlistenFn } from \"@tauri-apps/api/event\";\n");
+
+    let import_path = calculate_relative_import(types_file_path, events_file_path);
+    let mut types_list: Vec<_> = events.iter().map(|e| ctx.format_type_name(e.type_name)).collect();
+    types_list.sort();
+    types_list.dedup();
+    output.push_str(&format!(
+        "import type {{ {} }} from \"{}\";\n",
+        types_list.join(", "),
+        import_path
+    ));
+
+    for event in &events {
+        output.push('\n');
+        output.push_str(&generate_event_helpers(event, ctx));
+    }
+
+    Some(output)
+}
+
+/// Generate the `listen<Event>` and `emit<Event>` functions for one event
+fn generate_event_helpers(event: &EventPayload, ctx: &GeneratorContext) -> String {
+    let suffix = event_suffix(event.event);
+    let payload = payload_type(event, ctx);
+
+    format!(
+        "/** Listen for the `{name}` event */\n\
+         export function {listen}(handler: EventCallback<{payload}>): Promise<UnlistenFn> {{\n  \
+         return listen<{payload}>(\"{name}\", handler);\n\
+         }}\n\n\
+         /** Emit the `{name}` event */\n\
+         export function {emit}(payload: {payload}): Promise<void> {{\n  \
+         return emit(\"{name}\", payload);\n\
+         }}\n",
+        name = event.event,
+        listen = ctx.format_function_name(&format!("listen{}", suffix)),
+        emit = ctx.format_function_name(&format!("emit{}", suffix)),
+        payload = payload,
+    )
+}
+
+/// PascalCase identifier part for an event name (`download-progress` -> `DownloadProgress`)
+fn event_suffix(event: &str) -> String {
+    // Tauri event names may also contain `/` and `:`
+    let snake: String = event
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    to_pascal_case(&snake)
+}
+
+/// Payload type as used in the helpers; generic parameters can't be known here, so they become `unknown`
+fn payload_type(event: &EventPayload, ctx: &GeneratorContext) -> String {
+    let name = ctx.format_type_name(event.type_name);
+    if event.generics == 0 {
+        return name;
+    }
+    format!("{}<{}>", name, vec!["unknown"; event.generics].join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::NamingConfig;
+    use crate::models::{Docs, EnumRepresentation, EnumVariant, StructKind, VariantData};
+    use std::path::PathBuf;
+
+    fn event_struct(name: &str, event: Option<&str>) -> RustStruct {
+        RustStruct {
+            name: name.to_string(),
+            generics: vec![],
+            fields: vec![],
+            source_file: PathBuf::from("events.rs"),
+            transparent: false,
+            kind: StructKind::Named,
+            docs: Docs::default(),
+            event: event.map(|e| e.to_string()),
+            ts_name: None,
+            export: false,
+        }
+    }
+
+    fn generate(structs: &[RustStruct], enums: &[RustEnum], ctx: &GeneratorContext) -> Option<String> {
+        generate_events_file(
+            structs,
+            enums,
+            Path::new("src/generated/types.ts"),
+            Path::new("src/generated/events.ts"),
+            ctx,
+        )
+    }
+
+    #[test]
+    fn test_no_events() {
+        let ctx = GeneratorContext::new(NamingConfig::default());
+        assert_eq!(generate(&[event_struct("User", None)], &[], &ctx), None);
+    }
+
+    #[test]
+    fn test_generate_event_helpers() {
+        let ctx = GeneratorContext::new(NamingConfig::default());
+        let output = generate(&[event_struct("DownloadProgress", Some("download-progress"))], &[], &ctx).unwrap();
+
+        assert!(output.contains("import { emit, listen } from \"@tauri-apps/api/event\";"));
+        assert!(output.contains("import type { EventCallback, UnlistenFn } from \"@tauri-apps/api/event\";"));
+        assert!(output.contains("import type { DownloadProgress } from \"./types\";"));
+        assert!(output.contains(
+            "export function listenDownloadProgress(handler: EventCallback<DownloadProgress>): Promise<UnlistenFn> {\n  return listen<DownloadProgress>(\"download-progress\", handler);\n}"
+        ));
+        assert!(output.contains(
+            "export function emitDownloadProgress(payload: DownloadProgress): Promise<void> {\n  return emit(\"download-progress\", payload);\n}"
+        ));
+    }
+
+    #[test]
+    fn test_enum_event_and_naming() {
+        let ctx = GeneratorContext::new(NamingConfig {
+            type_prefix: "I".to_string(),
+            ..Default::default()
+        });
+        let session = RustEnum {
+            name: "SessionState".to_string(),
+            generics: vec![],
+            variants: vec![EnumVariant {
+                name: "LoggedOut".to_string(),
+                data: VariantData::Unit,
+                has_explicit_rename: false,
+                docs: Docs::default(),
+            }],
+            source_file: PathBuf::from("events.rs"),
+            representation: EnumRepresentation::External,
+            docs: Docs::default(),
+            event: Some("auth:session-changed".to_string()),
+            ts_name: None,
+            export: false,
+        };
+
+        let output = generate(&[], &[session], &ctx).unwrap();
+        assert!(output.contains("import type { ISessionState } from \"./types\";"));
+        assert!(output.contains("export function listenAuthSessionChanged(handler: EventCallback<ISessionState>)"));
+        assert!(output.contains("return emit(\"auth:session-changed\", payload);"));
+    }
+
+    #[test]
+    fn test_generic_payload_uses_unknown() {
+        let ctx = GeneratorContext::new(NamingConfig::default());
+        let mut progress = event_struct("Progress", Some("progress"));
+        progress.generics = vec!["T".to_string()];
+
+        let output = generate(&[progress], &[], &ctx).unwrap();
+        assert!(output.contains("export function emitProgress(payload: Progress<unknown>): Promise<void>"));
+    }
+}
@@ -0,0 +1,92 @@
+use crate::models::Docs;
+
+/// Render a JSDoc block for `docs`, or an empty string if there is nothing to document
+///
+/// `tags` (e.g. `@param id The user's ID`) follow the description, before `@deprecated`;
+/// every line of the block is prefixed with `indent`.
+pub fn render_jsdoc(docs: &Docs, tags: &[String], indent: &str) -> String {
+    let mut lines: Vec<String> = docs.lines.iter().map(|l| escape(l.trim_end())).collect();
+
+    let mut tags: Vec<String> = tags.iter().map(|t| escape(t.trim_end())).collect();
+    if let Some(note) = &docs.deprecated {
+        tags.push(format!("@deprecated {}", escape(note)).trim_end().to_string());
+    }
+
+    if lines.is_empty() && tags.is_empty() {
+        return String::new();
+    }
+
+    // Keep short single-line docs compact
+    if tags.is_empty() && lines.len() == 1 {
+        return format!("{}/** {} */\n", indent, lines[0]);
+    }
+
+    if !lines.is_empty() && !tags.is_empty() {
+        lines.push(String::new());
+    }
+    lines.extend(tags);
+
+    let mut output = format!("{}/**\n", indent);
+    for line in lines {
+        if line.is_empty() {
+            output.push_str(&format!("{} *\n", indent));
+        } else {
+            output.push_str(&format!("{} * {}\n", indent, line));
+        }
+    }
+    output.push_str(&format!("{} */\n", indent));
+    output
+}
+
+/// Keep doc text from terminating the comment early
+fn escape(text: &str) -> String {
+    text.replace("*/", "*\\/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn docs(lines: &[&str]) -> Docs {
+        Docs {
+            lines: lines.iter().map(|l| l.to_string()).collect(),
+            deprecated: None,
+        }
+    }
+
+    #[test]
+    fn test_empty_docs_render_nothing() {
+        assert_eq!(render_jsdoc(&Docs::default(), &[], ""), "");
+    }
+
+    #[test]
+    fn test_single_line_is_compact() {
+        assert_eq!(render_jsdoc(&docs(&["A user"]), &[], "  "), "  /** A user */\n");
+    }
+
+    #[test]
+    fn test_multi_line_with_tags() {
+        let mut d = docs(&["Fetch a user.", "", "Hits the database."]);
+        d.deprecated = Some("use getUserV2".to_string());
+        let tags = vec!["@param userId The user's ID".to_string()];
+
+        assert_eq!(
+            render_jsdoc(&d, &tags, ""),
+            "/**\n * Fetch a user.\n *\n * Hits the database.\n *\n * @param userId The user's ID\n * @deprecated use getUserV2\n */\n"
+        );
+    }
+
+    #[test]
+    fn test_deprecated_without_note() {
+        let d = Docs {
+            lines: Vec::new(),
+            deprecated: Some(String::new()),
+        };
+        assert_eq!(render_jsdoc(&d, &[], ""), "/**\n * @deprecated\n */\n");
+    }
+
+    #[test]
+    fn test_comment_terminator_is_escaped() {
+        assert_eq!(render_jsdoc(&docs(&["a */ b"]), &[], ""), "/** a *\\/ b */\n");
+    }
+}
@@ -1,8 +1,15 @@
 pub mod commands_gen;
+pub mod events_gen;
+mod jsdoc;
 pub mod type_mapper;
 pub mod types_gen;
 
-use crate::config::NamingConfig;
+use crate::config::{ErrorHandling, NamingConfig, TypeMapping};
+use crate::models::RustType;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// First line of every generated file
+pub const GENERATED_HEADER: &str = "// This file was auto-generated by tauri-ts-generator\n";
 
 /// Context for code generation
 pub struct GeneratorContext {
@@ -10,6 +17,14 @@ pub struct GeneratorContext {
     pub naming: NamingConfig,
     /// Set of custom type names that are available
     pub custom_types: std::collections::HashSet<String>,
+    /// Fixed-size arrays up to this length are emitted as TS tuples
+    pub max_array_tuple_len: usize,
+    /// How commands returning `Result` report errors
+    pub error_handling: ErrorHandling,
+    /// `[types]` config entries
+    pub type_mappings: BTreeMap<String, TypeMapping>,
+    /// TypeScript names given with `#[ts(rename = "...")]`, by Rust name
+    pub ts_names: std::collections::HashMap<String, String>,
 }
 
 impl GeneratorContext {
@@ -17,6 +32,10 @@ impl GeneratorContext {
         Self {
             naming,
             custom_types: std::collections::HashSet::new(),
+            max_array_tuple_len: 16,
+            error_handling: ErrorHandling::Throw,
+            type_mappings: BTreeMap::new(),
+            ts_names: std::collections::HashMap::new(),
         }
     }
 
@@ -30,8 +49,16 @@ impl GeneratorContext {
         self.custom_types.contains(name)
     }
 
-    /// Apply naming configuration to a type name
+    /// Use `ts_name` as is for the custom type `name`
+    pub fn rename_type(&mut self, name: &str, ts_name: &str) {
+        self.ts_names.insert(name.to_string(), ts_name.to_string());
+    }
+
+    /// Apply naming configuration to a type name; names from `#[ts(rename)]` are kept as is
     pub fn format_type_name(&self, name: &str) -> String {
+        if let Some(ts_name) = self.ts_names.get(name) {
+            return ts_name.clone();
+        }
         format!(
             "{}{}{}",
             self.naming.type_prefix, name, self.naming.type_suffix
@@ -46,3 +73,57 @@ impl GeneratorContext {
         )
     }
 }
+
+/// Imports needed by `[types]` mappings used in `ty`: module -> imported names
+pub(crate) fn collect_mapped_imports(
+    ty: &RustType,
+    ctx: &GeneratorContext,
+    imports: &mut BTreeMap<String, BTreeSet<String>>,
+) {
+    match ty {
+        RustType::Mapped(key) => {
+            if let Some(mapping) = ctx.type_mappings.get(key) {
+                if let Some(module) = mapping.import() {
+                    // Import the type's name, not its arguments (`MoneyDto<string>` -> `MoneyDto`)
+                    let name: String = mapping
+                        .ts()
+                        .chars()
+                        .take_while(|c| c.is_alphanumeric() || *c == '_' || *c == '$')
+                        .collect();
+                    imports.entry(module.to_string()).or_default().insert(name);
+                }
+            }
+        }
+        RustType::Vec(inner) | RustType::Option(inner) | RustType::Channel(inner) => {
+            collect_mapped_imports(inner, ctx, imports)
+        }
+        RustType::Array { elem, .. } => collect_mapped_imports(elem, ctx, imports),
+        RustType::Result { ok, err } => {
+            collect_mapped_imports(ok, ctx, imports);
+            if let Some(err) = err {
+                collect_mapped_imports(err, ctx, imports);
+            }
+        }
+        RustType::HashMap { key, value } => {
+            collect_mapped_imports(key, ctx, imports);
+            collect_mapped_imports(value, ctx, imports);
+        }
+        RustType::Tuple(types) | RustType::CustomGeneric { args: types, .. } => {
+            for t in types {
+                collect_mapped_imports(t, ctx, imports);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Render `import type { ... } from "..."` lines for mapped types
+pub(crate) fn render_mapped_imports(imports: &BTreeMap<String, BTreeSet<String>>) -> String {
+    imports
+        .iter()
+        .map(|(module, names)| {
+            let names: Vec<_> = names.iter().map(String::as_str).collect();
+            format!("import type {{ {} }} from \"{}\";\n", names.join(", "), module)
+        })
+        .collect()
+}
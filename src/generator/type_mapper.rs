@@ -22,15 +22,31 @@ pub fn rust_to_typescript(rust_type: &RustType, ctx: &GeneratorContext) -> Strin
             }
         }
 
+        RustType::Array { elem, len } => {
+            let elem_ts = rust_to_typescript(elem, ctx);
+            if *len <= ctx.max_array_tuple_len {
+                // Small fixed-size arrays become fixed-length tuples
+                format!("[{}]", vec![elem_ts; *len].join(", "))
+            } else if elem_ts.contains('|') {
+                format!("({})[]", elem_ts)
+            } else {
+                format!("{}[]", elem_ts)
+            }
+        }
+
         RustType::Option(inner) => {
             let inner_ts = rust_to_typescript(inner, ctx);
             format!("{} | null", inner_ts)
         }
 
-        RustType::Result(ok) => {
-            // For Result types, we return the Ok type
-            // The error will be handled by Promise rejection
-            rust_to_typescript(ok, ctx)
+        RustType::Result { ok, err } => {
+            // Serde serializes Result externally tagged. Command return values are
+            // unwrapped by the commands generator before reaching this point.
+            let err_ts = match err {
+                Some(err) => rust_to_typescript(err, ctx),
+                None => "unknown".to_string(),
+            };
+            format!("{{ Ok: {} }} | {{ Err: {} }}", rust_to_typescript(ok, ctx), err_ts)
         }
 
         RustType::HashMap { key, value } => {
@@ -39,12 +55,14 @@ pub fn rust_to_typescript(rust_type: &RustType, ctx: &GeneratorContext) -> Strin
             
             // Check if strict key usage is safe for TypeScript Record
             let use_param_key = match &**key {
-                // bool keys become strings in JSON ("true"/"false") but are invalid in TS Record<bool, ...>
-                RustType::Primitive(p) if p == "bool" => false, 
-                // numbers/strings are fine
-                RustType::Primitive(_) => true,
+                // bool keys become strings in JSON ("true"/"false") but are invalid in TS Record<bool, ...>,
+                // and object-shaped known types (Duration, ...) can't be keys either
+                RustType::Primitive(p) => {
+                    matches!(known_types::primitive_to_typescript(p), Some("string" | "number"))
+                }
+                RustType::Mapped(_) => matches!(key_ts.as_str(), "string" | "number"),
                 // Custom types (enums, newtypes) are assumed to be valid string/number keys
-                RustType::Custom(_) => true,
+                RustType::Custom(_) | RustType::CustomGeneric { .. } => true,
                 // Generic Params are assumed to be valid
                 RustType::Generic(_) => true,
                 // Complex types (Vec, Option, etc) cannot be keys in TS Record
@@ -58,6 +76,20 @@ pub fn rust_to_typescript(rust_type: &RustType, ctx: &GeneratorContext) -> Strin
             }
         }
 
+        RustType::Binary => "ArrayBuffer".to_string(),
+
+        RustType::Verbatim(ts) => ts.clone(),
+
+        RustType::Mapped(key) => match ctx.type_mappings.get(key) {
+            Some(mapping) => mapping.ts().to_string(),
+            None => {
+                eprintln!("Warning: No [types] mapping for '{}', using 'unknown'", key);
+                "unknown".to_string()
+            }
+        },
+
+        RustType::Channel(inner) => format!("Channel<{}>", rust_to_typescript(inner, ctx)),
+
         RustType::Tuple(types) => {
             if types.is_empty() {
                 "void".to_string()
@@ -76,6 +108,16 @@ pub fn rust_to_typescript(rust_type: &RustType, ctx: &GeneratorContext) -> Strin
             }
         }
 
+        RustType::CustomGeneric { name, args } => {
+            let base = if ctx.is_custom_type(name) {
+                ctx.format_type_name(name)
+            } else {
+                name.clone()
+            };
+            let args_ts: Vec<_> = args.iter().map(|a| rust_to_typescript(a, ctx)).collect();
+            format!("{}<{}>", base, args_ts.join(", "))
+        }
+
         RustType::Generic(name) => {
             // Generic type parameters are passed through as-is (T, U, etc.)
             name.clone()
@@ -107,7 +149,9 @@ fn primitive_to_typescript(name: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::NamingConfig;
+    use crate::config::{NamingConfig, TypeMapping};
+    use crate::generator::{collect_mapped_imports, render_mapped_imports};
+    use std::collections::BTreeMap;
 
     fn default_ctx() -> GeneratorContext {
         GeneratorContext::new(NamingConfig::default())
@@ -168,6 +212,32 @@ mod tests {
         assert_eq!(rust_to_typescript(&ty, &ctx), "number[][]");
     }
 
+    #[test]
+    fn test_small_array_to_tuple() {
+        let ty = RustType::Array {
+            elem: Box::new(RustType::Primitive("f32".to_string())),
+            len: 3,
+        };
+        assert_eq!(rust_to_typescript(&ty, &default_ctx()), "[number, number, number]");
+    }
+
+    #[test]
+    fn test_large_array_to_typescript_array() {
+        let ty = RustType::Array {
+            elem: Box::new(RustType::Primitive("u8".to_string())),
+            len: 32,
+        };
+        assert_eq!(rust_to_typescript(&ty, &default_ctx()), "number[]");
+
+        let mut ctx = default_ctx();
+        ctx.max_array_tuple_len = 2;
+        let ty = RustType::Array {
+            elem: Box::new(RustType::Option(Box::new(RustType::Primitive("i32".to_string())))),
+            len: 4,
+        };
+        assert_eq!(rust_to_typescript(&ty, &ctx), "(number | null)[]");
+    }
+
     #[test]
     fn test_option_to_typescript() {
         let ctx = default_ctx();
@@ -185,17 +255,23 @@ mod tests {
     #[test]
     fn test_result_to_typescript() {
         let ctx = ctx_with_type("User");
-        let ty = RustType::Result(Box::new(RustType::Custom("User".to_string())));
-        assert_eq!(rust_to_typescript(&ty, &ctx), "User");
+        let ty = RustType::Result {
+            ok: Box::new(RustType::Custom("User".to_string())),
+            err: Some(Box::new(RustType::Primitive("String".to_string()))),
+        };
+        assert_eq!(rust_to_typescript(&ty, &ctx), "{ Ok: User } | { Err: string }");
     }
 
     #[test]
     fn test_result_with_vec() {
         let ctx = ctx_with_type("Item");
-        let ty = RustType::Result(Box::new(RustType::Vec(Box::new(RustType::Custom(
-            "Item".to_string(),
-        )))));
-        assert_eq!(rust_to_typescript(&ty, &ctx), "Item[]");
+        let ty = RustType::Result {
+            ok: Box::new(RustType::Vec(Box::new(RustType::Custom(
+                "Item".to_string(),
+            )))),
+            err: Some(Box::new(RustType::Primitive("String".to_string()))),
+        };
+        assert_eq!(rust_to_typescript(&ty, &ctx), "{ Ok: Item[] } | { Err: string }");
     }
 
     #[test]
@@ -257,6 +333,45 @@ mod tests {
         assert_eq!(rust_to_typescript(&ty, &ctx), "UnknownType");
     }
 
+    #[test]
+    fn test_custom_generic_type() {
+        let mut ctx = ctx_with_type("Page");
+        ctx.register_type("User");
+        let ty = RustType::CustomGeneric {
+            name: "Page".to_string(),
+            args: vec![RustType::Custom("User".to_string())],
+        };
+        assert_eq!(rust_to_typescript(&ty, &ctx), "Page<User>");
+    }
+
+    #[test]
+    fn test_custom_generic_nested_args() {
+        let mut ctx = ctx_with_type("ApiResponse");
+        ctx.register_type("Item");
+        let ty = RustType::CustomGeneric {
+            name: "ApiResponse".to_string(),
+            args: vec![RustType::Vec(Box::new(RustType::Custom("Item".to_string())))],
+        };
+        assert_eq!(rust_to_typescript(&ty, &ctx), "ApiResponse<Item[]>");
+    }
+
+    #[test]
+    fn test_custom_generic_with_naming() {
+        let mut ctx = GeneratorContext::new(NamingConfig {
+            type_prefix: "I".to_string(),
+            type_suffix: "".to_string(),
+            function_prefix: "".to_string(),
+            function_suffix: "".to_string(),
+        });
+        ctx.register_type("Page");
+        ctx.register_type("User");
+        let ty = RustType::CustomGeneric {
+            name: "Page".to_string(),
+            args: vec![RustType::Custom("User".to_string())],
+        };
+        assert_eq!(rust_to_typescript(&ty, &ctx), "IPage<IUser>");
+    }
+
     #[test]
     fn test_generic_passthrough() {
         let ctx = default_ctx();
@@ -306,8 +421,27 @@ mod tests {
     }
 
     #[test]
-    fn test_duration_to_number() {
-        assert_eq!(primitive_to_typescript("Duration"), "number");
+    fn test_std_time_object_shapes() {
+        assert_eq!(primitive_to_typescript("Duration"), "{ secs: number; nanos: number }");
+        assert_eq!(
+            primitive_to_typescript("std::time::SystemTime"),
+            "{ secs_since_epoch: number; nanos_since_epoch: number }"
+        );
+    }
+
+    #[test]
+    fn test_non_zero_integers() {
+        assert_eq!(primitive_to_typescript("NonZeroU32"), "number");
+        assert_eq!(primitive_to_typescript("std::num::NonZero"), "number");
+    }
+
+    #[test]
+    fn test_object_shaped_key_falls_back_to_string() {
+        let ty = RustType::HashMap {
+            key: Box::new(RustType::Primitive("Duration".to_string())),
+            value: Box::new(RustType::Primitive("u32".to_string())),
+        };
+        assert_eq!(rust_to_typescript(&ty, &default_ctx()), "Record<string, number>");
     }
 
     #[test]
@@ -349,9 +483,61 @@ mod tests {
     #[test]
     fn test_complex_nested_type() {
         let ctx = ctx_with_type("User");
-        let ty = RustType::Result(Box::new(RustType::Vec(Box::new(RustType::Option(
-            Box::new(RustType::Custom("User".to_string())),
-        )))));
-        assert_eq!(rust_to_typescript(&ty, &ctx), "(User | null)[]");
+        let ty = RustType::Result {
+            ok: Box::new(RustType::Vec(Box::new(RustType::Option(
+                Box::new(RustType::Custom("User".to_string())),
+            )))),
+            err: Some(Box::new(RustType::Primitive("String".to_string()))),
+        };
+        assert_eq!(
+            rust_to_typescript(&ty, &ctx),
+            "{ Ok: (User | null)[] } | { Err: string }"
+        );
+    }
+
+    #[test]
+    fn test_result_inside_vec() {
+        let ctx = ctx_with_type("Item");
+        let ty = RustType::Vec(Box::new(RustType::Result {
+            ok: Box::new(RustType::Custom("Item".to_string())),
+            err: None,
+        }));
+        assert_eq!(rust_to_typescript(&ty, &ctx), "({ Ok: Item } | { Err: unknown })[]");
+    }
+
+    #[test]
+    fn test_channel() {
+        let ctx = ctx_with_type("ProgressEvent");
+        let ty = RustType::Channel(Box::new(RustType::Custom("ProgressEvent".to_string())));
+        assert_eq!(rust_to_typescript(&ty, &ctx), "Channel<ProgressEvent>");
+    }
+
+    #[test]
+    fn test_mapped_types() {
+        let mut ctx = default_ctx();
+        ctx.type_mappings.insert("ulid::Ulid".to_string(), TypeMapping::Inline("string".to_string()));
+        ctx.type_mappings.insert(
+            "crate::money::Money".to_string(),
+            TypeMapping::Imported {
+                ts: "MoneyDto".to_string(),
+                import: Some("../money".to_string()),
+            },
+        );
+
+        let ty = RustType::Vec(Box::new(RustType::Mapped("crate::money::Money".to_string())));
+        assert_eq!(rust_to_typescript(&ty, &ctx), "MoneyDto[]");
+
+        let ty = RustType::HashMap {
+            key: Box::new(RustType::Mapped("ulid::Ulid".to_string())),
+            value: Box::new(RustType::Mapped("crate::money::Money".to_string())),
+        };
+        assert_eq!(rust_to_typescript(&ty, &ctx), "Record<string, MoneyDto>");
+
+        let mut imports = BTreeMap::new();
+        collect_mapped_imports(&ty, &ctx, &mut imports);
+        assert_eq!(
+            render_mapped_imports(&imports),
+            "import type { MoneyDto } from \"../money\";\n"
+        );
     }
 }
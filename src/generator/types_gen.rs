@@ -1,20 +1,60 @@
-use crate::models::{EnumRepresentation, RustEnum, RustStruct, VariantData};
-use crate::utils::to_camel_case;
-
-use super::{type_mapper::rust_to_typescript, GeneratorContext};
+use crate::models::{
+    EnumRepresentation, FieldPresence, RustEnum, RustStruct, RustTypeAlias, StructField, StructKind,
+    VariantData,
+};
+use crate::utils::property_name;
+use std::collections::BTreeMap;
+
+use super::{
+    collect_mapped_imports, jsdoc::render_jsdoc, render_mapped_imports, type_mapper::rust_to_typescript,
+    GeneratorContext, GENERATED_HEADER,
+};
 
 /// Generate TypeScript types file content
 pub fn generate_types_file(
     structs: &[RustStruct],
     enums: &[RustEnum],
+    type_aliases: &[RustTypeAlias],
     ctx: &GeneratorContext,
 ) -> String {
     let mut output = String::new();
 
     // Add header comment
-    output.push_str("// This file was auto-generated by tauri-ts-generator\n");
+    output.push_str(GENERATED_HEADER);
     output.push_str("// Do not edit this file manually\n\n");
 
+    // Import types mapped in the [types] config
+    let mut imports = BTreeMap::new();
+    for s in structs {
+        for field in &s.fields {
+            collect_mapped_imports(&field.ty, ctx, &mut imports);
+        }
+    }
+    for e in enums {
+        for variant in &e.variants {
+            match &variant.data {
+                VariantData::Unit => {}
+                VariantData::Tuple(types) => {
+                    for ty in types {
+                        collect_mapped_imports(ty, ctx, &mut imports);
+                    }
+                }
+                VariantData::Struct(fields) => {
+                    for field in fields {
+                        collect_mapped_imports(&field.ty, ctx, &mut imports);
+                    }
+                }
+            }
+        }
+    }
+    for alias in type_aliases {
+        collect_mapped_imports(&alias.target, ctx, &mut imports);
+    }
+    if !imports.is_empty() {
+        output.push_str(&render_mapped_imports(&imports));
+        output.push('\n');
+    }
+
     // Generate interfaces for structs
     for s in structs {
         output.push_str(&generate_interface(s, ctx));
@@ -27,12 +67,37 @@ pub fn generate_types_file(
         output.push('\n');
     }
 
+    // Generate type aliases
+    for alias in type_aliases {
+        output.push_str(&generate_type_alias(alias, ctx));
+        output.push('\n');
+    }
+
     output
 }
 
+/// Generate a TypeScript type alias from a Rust type alias
+fn generate_type_alias(alias: &RustTypeAlias, ctx: &GeneratorContext) -> String {
+    let type_name = ctx.format_type_name(&alias.name);
+
+    let generics_str = if alias.generics.is_empty() {
+        String::new()
+    } else {
+        format!("<{}>", alias.generics.join(", "))
+    };
+
+    format!(
+        "{}export type {}{} = {};\n",
+        render_jsdoc(&alias.docs, &[], ""),
+        type_name,
+        generics_str,
+        rust_to_typescript(&alias.target, ctx)
+    )
+}
+
 /// Generate a TypeScript interface from a Rust struct
 fn generate_interface(s: &RustStruct, ctx: &GeneratorContext) -> String {
-    let mut output = String::new();
+    let mut output = render_jsdoc(&s.docs, &[], "");
 
     let interface_name = ctx.format_type_name(&s.name);
 
@@ -43,36 +108,78 @@ fn generate_interface(s: &RustStruct, ctx: &GeneratorContext) -> String {
         format!("<{}>", s.generics.join(", "))
     };
 
-    output.push_str(&format!(
-        "export interface {}{} {{\n",
-        interface_name, generics_str
-    ));
+    // #[serde(transparent)] structs serialize exactly like their single field
+    if s.transparent {
+        if let Some(field) = s.fields.iter().find(|f| f.is_serialized()) {
+            output.push_str(&format!(
+                "export type {}{} = {};\n",
+                interface_name,
+                generics_str,
+                rust_to_typescript(&field.ty, ctx)
+            ));
+            return output;
+        }
+    }
 
-    for field in &s.fields {
-        // If use_optional is true and type is Option<T>, generate field?: T instead of field: T | null
-        let (optional_marker, ts_type) = if field.use_optional {
-            if let crate::models::RustType::Option(inner) = &field.ty {
-                let ts_type = rust_to_typescript(inner, ctx);
-                ("?", ts_type)
+    // Tuple and unit structs don't serialize as objects
+    let alias_target = match s.kind {
+        StructKind::Named => None,
+        StructKind::Unit => Some("null".to_string()),
+        StructKind::Tuple => {
+            let ts_types: Vec<_> = s
+                .fields
+                .iter()
+                .filter(|f| f.is_serialized())
+                .map(|f| rust_to_typescript(&f.ty, ctx))
+                .collect();
+            // Newtype structs serialize as their inner value; serde decides this from the
+            // declared field count, so `Pair(#[serde(skip)] u32, String)` stays a tuple
+            if s.fields.len() == 1 && ts_types.len() == 1 {
+                Some(ts_types[0].clone())
             } else {
-                let ts_type = rust_to_typescript(&field.ty, ctx);
-                ("", ts_type)
+                Some(format!("[{}]", ts_types.join(", ")))
             }
-        } else {
-            let ts_type = rust_to_typescript(&field.ty, ctx);
-            ("", ts_type)
-        };
-        // If serde rename was explicitly set, use the name as-is
-        // Otherwise, convert to camelCase
-        let field_name = if field.has_explicit_rename {
-            field.name.clone()
-        } else {
-            to_camel_case(&field.name)
-        };
+        }
+    };
+    if let Some(target) = alias_target {
         output.push_str(&format!(
-            "  {}{}: {};\n",
-            field_name, optional_marker, ts_type
+            "export type {}{} = {};\n",
+            interface_name, generics_str, target
         ));
+        return output;
+    }
+
+    let fields: Vec<_> = s.fields.iter().filter(|f| f.is_serialized()).collect();
+    let (flattened, own): (Vec<&StructField>, Vec<&StructField>) = fields.iter().partition(|f| f.flatten);
+
+    // #[serde(flatten)] fields can't be expressed in an interface, so emit an intersection
+    if !flattened.is_empty() {
+        let mut parts = Vec::new();
+        if !own.is_empty() {
+            let members: Vec<_> = own
+                .iter()
+                .map(|field| generate_member(field, ctx))
+                .collect();
+            parts.push(format!("{{\n{}}}", members.concat()));
+        }
+        parts.extend(flattened.iter().map(|field| generate_flattened_type(field, &fields, ctx)));
+
+        output.push_str(&format!(
+            "export type {}{} = {};\n",
+            interface_name,
+            generics_str,
+            parts.join(" & ")
+        ));
+        return output;
+    }
+
+    output.push_str(&format!(
+        "export interface {}{} {{\n",
+        interface_name, generics_str
+    ));
+
+    for field in own {
+        output.push_str(&generate_member(field, ctx));
     }
 
     output.push_str("}\n");
@@ -82,7 +189,7 @@ fn generate_interface(s: &RustStruct, ctx: &GeneratorContext) -> String {
 
 /// Generate a TypeScript type from a Rust enum
 fn generate_enum_type(e: &RustEnum, ctx: &GeneratorContext) -> String {
-    let mut output = String::new();
+    let mut output = render_jsdoc(&e.docs, &[], "");
 
     let type_name = ctx.format_type_name(&e.name);
 
@@ -96,7 +203,13 @@ fn generate_enum_type(e: &RustEnum, ctx: &GeneratorContext) -> String {
     let variants: Vec<String> = e
         .variants
         .iter()
-        .map(|variant| generate_variant(variant, &e.representation, ctx))
+        .map(|variant| {
+            format!(
+                "{}  | {}",
+                render_jsdoc(&variant.docs, &[], "  "),
+                generate_variant(variant, &e.representation, ctx)
+            )
+        })
         .collect();
 
     if variants.is_empty() {
@@ -106,10 +219,10 @@ fn generate_enum_type(e: &RustEnum, ctx: &GeneratorContext) -> String {
         ));
     } else {
         output.push_str(&format!(
-            "export type {}{} =\n  | {};\n",
+            "export type {}{} =\n{};\n",
             type_name,
             generics_str,
-            variants.join("\n  | ")
+            variants.join("\n")
         ));
     }
 
@@ -131,33 +244,31 @@ fn generate_variant(
                 } else {
                     format!("[{}]", ts_types.join(", "))
                 };
-                format!("{{ {}: {} }}", variant.name, inner)
+                format!("{{ {}: {} }}", property_name(&variant.name), inner)
             }
             VariantData::Struct(fields) => {
-                let inner = generate_struct_body(fields, ctx);
-                format!("{{ {}: {} }}", variant.name, inner)
+                let inner = generate_struct_body(fields, None, ctx);
+                format!("{{ {}: {} }}", property_name(&variant.name), inner)
             }
         },
         EnumRepresentation::Internal { tag } => {
             match &variant.data {
-                VariantData::Unit => format!("{{ {}: \"{}\" }}", tag, variant.name),
+                VariantData::Unit => format!("{{ {}: \"{}\" }}", property_name(tag), variant.name),
                 VariantData::Struct(fields) => {
-                    let mut body = generate_struct_body(fields, ctx);
-                    // Remove opening brace and insert tag
-                    body.remove(0); // remove '{'
-                    format!("{{ {}: \"{}\",{}", tag, variant.name, body)
+                    let tag_member = format!("{}: \"{}\"", property_name(tag), variant.name);
+                    generate_struct_body(fields, Some(tag_member), ctx)
                 }
                 VariantData::Tuple(_) => {
                     // Internal tagging doesn't support tuples strictly speaking (unless newtype around struct)
                     // We'll fallback to just the tag for now or maybe error?
                     // For safety let's just emit { tag: "Name" } & Partial<Tuple> ??
                     // Let's assume user knows what they are doing and it's likely not used with Tuples
-                    format!("{{ {}: \"{}\" /* Tuple variants not fully supported in internal tagging */ }}", tag, variant.name)
+                    format!("{{ {}: \"{}\" /* Tuple variants not fully supported in internal tagging */ }}", property_name(tag), variant.name)
                 }
             }
         }
         EnumRepresentation::Adjacent { tag, content } => match &variant.data {
-            VariantData::Unit => format!("{{ {}: \"{}\" }}", tag, variant.name),
+            VariantData::Unit => format!("{{ {}: \"{}\" }}", property_name(tag), variant.name),
             VariantData::Tuple(types) => {
                 let ts_types: Vec<_> = types.iter().map(|t| rust_to_typescript(t, ctx)).collect();
                 let inner = if ts_types.len() == 1 {
@@ -167,14 +278,14 @@ fn generate_variant(
                 };
                 format!(
                     "{{ {}: \"{}\"; {}: {} }}",
-                    tag, variant.name, content, inner
+                    property_name(tag), variant.name, property_name(content), inner
                 )
             }
             VariantData::Struct(fields) => {
-                let inner = generate_struct_body(fields, ctx);
+                let inner = generate_struct_body(fields, None, ctx);
                 format!(
                     "{{ {}: \"{}\"; {}: {} }}",
-                    tag, variant.name, content, inner
+                    property_name(tag), variant.name, property_name(content), inner
                 )
             }
         },
@@ -188,44 +299,94 @@ fn generate_variant(
                     format!("[{}]", ts_types.join(", "))
                 }
             }
-            VariantData::Struct(fields) => generate_struct_body(fields, ctx),
+            VariantData::Struct(fields) => generate_struct_body(fields, None, ctx),
         },
     }
 }
 
-fn generate_struct_body(fields: &[crate::models::StructField], ctx: &GeneratorContext) -> String {
-    let mut params = Vec::new();
-    for field in fields {
-        // If use_optional is true and type is Option<T>, generate field?: T instead of field: T | null
-        let (optional_marker, ts_type) = if field.use_optional {
-            if let crate::models::RustType::Option(inner) = &field.ty {
-                let ts_type = rust_to_typescript(inner, ctx);
-                ("?", ts_type)
-            } else {
-                let ts_type = rust_to_typescript(&field.ty, ctx);
-                ("", ts_type)
-            }
-        } else {
-            let ts_type = rust_to_typescript(&field.ty, ctx);
-            ("", ts_type)
-        };
-        // If serde rename was explicitly set, use the name as-is
-        // Otherwise, convert to camelCase
-        let field_name = if field.has_explicit_rename {
-            field.name.clone()
-        } else {
-            to_camel_case(&field.name)
-        };
-        params.push(format!("{}{}: {}", field_name, optional_marker, ts_type));
+/// Generate the inline object type of a struct variant, led by the internal tag member if any
+fn generate_struct_body(fields: &[StructField], tag_member: Option<String>, ctx: &GeneratorContext) -> String {
+    let fields: Vec<_> = fields.iter().filter(|f| f.is_serialized()).collect();
+    let params: Vec<_> = fields
+        .iter()
+        .filter(|f| !f.flatten)
+        .map(|field| generate_field(field, ctx))
+        .collect();
+    let members = match tag_member {
+        Some(tag_member) if params.is_empty() => tag_member,
+        Some(tag_member) => format!("{}, {}", tag_member, params.join("; ")),
+        None => params.join("; "),
+    };
+
+    let mut parts = Vec::new();
+    // Variants made only of flattened fields need no object of their own
+    if !members.is_empty() || fields.iter().all(|f| !f.flatten) {
+        parts.push(format!("{{ {} }}", members));
+    }
+    parts.extend(
+        fields
+            .iter()
+            .filter(|f| f.flatten)
+            .map(|field| generate_flattened_type(field, &fields, ctx)),
+    );
+    parts.join(" & ")
+}
+
+/// Generate the intersection member for a #[serde(flatten)] field, given all serialized `fields`
+fn generate_flattened_type(field: &StructField, fields: &[&StructField], ctx: &GeneratorContext) -> String {
+    match &field.ty {
+        // Flattened maps collect all remaining keys. The index signature also covers the
+        // other fields, so it needs a value type they all share
+        crate::models::RustType::HashMap { value, .. } => {
+            let value = rust_to_typescript(value, ctx);
+            let compatible = fields
+                .iter()
+                .filter(|other| !std::ptr::eq(**other, field))
+                .all(|other| !other.flatten && field_type(other, ctx).1 == value);
+            format!("Record<string, {}>", if compatible { value.as_str() } else { "unknown" })
+        }
+        // A flattened None contributes no keys at all
+        crate::models::RustType::Option(inner) => {
+            format!("Partial<{}>", rust_to_typescript(inner, ctx))
+        }
+        ty => rust_to_typescript(ty, ctx),
+    }
+}
+
+/// Generate an interface member line, preceded by the field's JSDoc
+fn generate_member(field: &StructField, ctx: &GeneratorContext) -> String {
+    format!(
+        "{}  {};\n",
+        render_jsdoc(&field.docs, &[], "  "),
+        generate_field(field, ctx)
+    )
+}
+
+/// Generate a single `name: type` member (without trailing separator)
+///
+/// The parser has already applied serde renaming; names that aren't identifiers are quoted.
+fn generate_field(field: &StructField, ctx: &GeneratorContext) -> String {
+    let (optional_marker, ts_type) = field_type(field, ctx);
+    format!("{}{}: {}", property_name(&field.name), optional_marker, ts_type)
+}
+
+/// The optional marker (`?` or nothing) and TypeScript type of a member
+fn field_type(field: &StructField, ctx: &GeneratorContext) -> (&'static str, String) {
+    // If use_optional is true and type is Option<T>, generate field?: T instead of field: T | null
+    match &field.ty {
+        crate::models::RustType::Option(inner) if field.use_optional => {
+            ("?", rust_to_typescript(inner, ctx))
+        }
+        ty if field.presence == FieldPresence::Optional => ("?", rust_to_typescript(ty, ctx)),
+        ty => ("", rust_to_typescript(ty, ctx)),
     }
-    format!("{{ {} }}", params.join("; "))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::config::NamingConfig;
-    use crate::models::{EnumVariant, RustType, StructField, VariantData};
+    use crate::models::{Docs, EnumVariant, RustType, StructField, VariantData};
     use std::path::PathBuf;
 
     fn test_path() -> PathBuf {
@@ -247,15 +408,27 @@ mod tests {
                     use_optional: false,
                     name: "id".to_string(),
                     ty: RustType::Primitive("i32".to_string()),
+                    presence: FieldPresence::Always,
+                    flatten: false,
+                    docs: Default::default(),
                 },
                 StructField {
                     has_explicit_rename: false,
                     use_optional: false,
                     name: "name".to_string(),
                     ty: RustType::Primitive("String".to_string()),
+                    presence: FieldPresence::Always,
+                    flatten: false,
+                    docs: Default::default(),
                 },
             ],
             source_file: test_path(),
+            transparent: false,
+            kind: StructKind::Named,
+            docs: Default::default(),
+            event: None,
+            ts_name: None,
+            export: false,
         };
 
         let ctx = default_ctx();
@@ -277,15 +450,27 @@ mod tests {
                     use_optional: false,
                     name: "data".to_string(),
                     ty: RustType::Generic("T".to_string()),
+                    presence: FieldPresence::Always,
+                    flatten: false,
+                    docs: Default::default(),
                 },
                 StructField {
                     has_explicit_rename: false,
                     use_optional: false,
                     name: "count".to_string(),
                     ty: RustType::Primitive("i32".to_string()),
+                    presence: FieldPresence::Always,
+                    flatten: false,
+                    docs: Default::default(),
                 },
             ],
             source_file: test_path(),
+            transparent: false,
+            kind: StructKind::Named,
+            docs: Default::default(),
+            event: None,
+            ts_name: None,
+            export: false,
         };
 
         let ctx = default_ctx();
@@ -307,15 +492,27 @@ mod tests {
                     use_optional: false,
                     name: "key".to_string(),
                     ty: RustType::Generic("K".to_string()),
+                    presence: FieldPresence::Always,
+                    flatten: false,
+                    docs: Default::default(),
                 },
                 StructField {
                     has_explicit_rename: false,
                     use_optional: false,
                     name: "value".to_string(),
                     ty: RustType::Generic("V".to_string()),
+                    presence: FieldPresence::Always,
+                    flatten: false,
+                    docs: Default::default(),
                 },
             ],
             source_file: test_path(),
+            transparent: false,
+            kind: StructKind::Named,
+            docs: Default::default(),
+            event: None,
+            ts_name: None,
+            export: false,
         };
 
         let ctx = default_ctx();
@@ -334,20 +531,27 @@ mod tests {
                     has_explicit_rename: false,
                     name: "Active".to_string(),
                     data: VariantData::Unit,
+                    docs: Default::default(),
                 },
                 EnumVariant {
                     has_explicit_rename: false,
                     name: "Inactive".to_string(),
                     data: VariantData::Unit,
+                    docs: Default::default(),
                 },
                 EnumVariant {
                     has_explicit_rename: false,
                     name: "Pending".to_string(),
                     data: VariantData::Unit,
+                    docs: Default::default(),
                 },
             ],
             source_file: test_path(),
             representation: EnumRepresentation::default(),
+            docs: Default::default(),
+            event: None,
+            ts_name: None,
+            export: false,
         };
 
         let ctx = default_ctx();
@@ -369,15 +573,21 @@ mod tests {
                     has_explicit_rename: false,
                     name: "Text".to_string(),
                     data: VariantData::Tuple(vec![RustType::Primitive("String".to_string())]),
+                    docs: Default::default(),
                 },
                 EnumVariant {
                     has_explicit_rename: false,
                     name: "Number".to_string(),
                     data: VariantData::Tuple(vec![RustType::Primitive("i32".to_string())]),
+                    docs: Default::default(),
                 },
             ],
             source_file: test_path(),
             representation: EnumRepresentation::default(),
+            docs: Default::default(),
+            event: None,
+            ts_name: None,
+            export: false,
         };
 
         let ctx = default_ctx();
@@ -403,18 +613,27 @@ mod tests {
                         use_optional: false,
                         name: "permissions".to_string(),
                         ty: RustType::Vec(Box::new(RustType::Primitive("String".to_string()))),
+                        presence: FieldPresence::Always,
+                        flatten: false,
+                        docs: Default::default(),
                     }]),
+                    docs: Default::default(),
                 },
                 EnumVariant {
                     has_explicit_rename: false,
                     name: "User".to_string(),
                     data: VariantData::Unit,
+                    docs: Default::default(),
                 },
             ],
             source_file: test_path(),
             representation: EnumRepresentation::Internal {
                 tag: "type".to_string(),
             },
+            docs: Default::default(),
+            event: None,
+            ts_name: None,
+            export: false,
         };
 
         let ctx = default_ctx();
@@ -426,7 +645,8 @@ mod tests {
     }
 
     #[test]
-    fn test_camel_case_field_names() {
+    fn test_field_names_used_as_is() {
+        // Field names are resolved by the parser; the generator must not re-case them
         let s = RustStruct {
             name: "User".to_string(),
             generics: vec![],
@@ -434,24 +654,37 @@ mod tests {
                 StructField {
                     has_explicit_rename: false,
                     use_optional: false,
-                    name: "user_id".to_string(),
+                    name: "userId".to_string(),
                     ty: RustType::Primitive("i32".to_string()),
+                    presence: FieldPresence::Always,
+                    flatten: false,
+                    docs: Default::default(),
                 },
                 StructField {
-                    has_explicit_rename: false,
+                    has_explicit_rename: true, // Simulate #[serde(rename_all = "snake_case")]
                     use_optional: false,
                     name: "first_name".to_string(),
                     ty: RustType::Primitive("String".to_string()),
+                    presence: FieldPresence::Always,
+                    flatten: false,
+                    docs: Default::default(),
                 },
             ],
             source_file: test_path(),
+            transparent: false,
+            kind: StructKind::Named,
+            docs: Default::default(),
+            event: None,
+            ts_name: None,
+            export: false,
         };
 
         let ctx = default_ctx();
         let output = generate_interface(&s, &ctx);
 
         assert!(output.contains("userId: number"));
-        assert!(output.contains("firstName: string"));
+        assert!(output.contains("first_name: string"));
+        assert!(!output.contains("firstName"));
     }
 
     #[test]
@@ -461,6 +694,12 @@ mod tests {
             generics: vec![],
             fields: vec![],
             source_file: test_path(),
+            transparent: false,
+            kind: StructKind::Named,
+            docs: Default::default(),
+            event: None,
+            ts_name: None,
+            export: false,
         };
 
         let ctx = default_ctx();
@@ -472,7 +711,7 @@ mod tests {
 
     #[test]
     fn test_generate_types_file_header() {
-        let output = generate_types_file(&[], &[], &default_ctx());
+        let output = generate_types_file(&[], &[], &[], &default_ctx());
 
         assert!(output.contains("// This file was auto-generated by tauri-ts-generator"));
         assert!(output.contains("// Do not edit this file manually"));
@@ -489,8 +728,17 @@ mod tests {
                     use_optional: false,
                     name: "id".to_string(),
                     ty: RustType::Primitive("i32".to_string()),
+                    presence: FieldPresence::Always,
+                    flatten: false,
+                    docs: Default::default(),
                 }],
                 source_file: test_path(),
+                transparent: false,
+                kind: StructKind::Named,
+                docs: Default::default(),
+                event: None,
+                ts_name: None,
+                export: false,
             },
             RustStruct {
                 name: "Item".to_string(),
@@ -500,8 +748,17 @@ mod tests {
                     use_optional: false,
                     name: "name".to_string(),
                     ty: RustType::Primitive("String".to_string()),
+                    presence: FieldPresence::Always,
+                    flatten: false,
+                    docs: Default::default(),
                 }],
                 source_file: test_path(),
+                transparent: false,
+                kind: StructKind::Named,
+                docs: Default::default(),
+                event: None,
+                ts_name: None,
+                export: false,
             },
         ];
 
@@ -512,13 +769,18 @@ mod tests {
                 has_explicit_rename: false,
                 name: "Active".to_string(),
                 data: VariantData::Unit,
+                docs: Default::default(),
             }],
             source_file: test_path(),
             representation: EnumRepresentation::default(),
+            docs: Default::default(),
+            event: None,
+            ts_name: None,
+            export: false,
         }];
 
         let ctx = default_ctx();
-        let output = generate_types_file(&structs, &enums, &ctx);
+        let output = generate_types_file(&structs, &enums, &[], &ctx);
 
         assert!(output.contains("export interface User"));
         assert!(output.contains("export interface Item"));
@@ -535,8 +797,17 @@ mod tests {
                 use_optional: false,
                 name: "email".to_string(),
                 ty: RustType::Option(Box::new(RustType::Primitive("String".to_string()))),
+                presence: FieldPresence::Always,
+                flatten: false,
+                docs: Default::default(),
             }],
             source_file: test_path(),
+            transparent: false,
+            kind: StructKind::Named,
+            docs: Default::default(),
+            event: None,
+            ts_name: None,
+            export: false,
         };
 
         let ctx = default_ctx();
@@ -555,8 +826,17 @@ mod tests {
                 use_optional: false,
                 name: "tags".to_string(),
                 ty: RustType::Vec(Box::new(RustType::Primitive("String".to_string()))),
+                presence: FieldPresence::Always,
+                flatten: false,
+                docs: Default::default(),
             }],
             source_file: test_path(),
+            transparent: false,
+            kind: StructKind::Named,
+            docs: Default::default(),
+            event: None,
+            ts_name: None,
+            export: false,
         };
 
         let ctx = default_ctx();
@@ -572,6 +852,12 @@ mod tests {
             generics: vec![],
             fields: vec![],
             source_file: test_path(),
+            transparent: false,
+            kind: StructKind::Named,
+            docs: Default::default(),
+            event: None,
+            ts_name: None,
+            export: false,
         };
 
         let ctx = GeneratorContext::new(NamingConfig {
@@ -593,25 +879,40 @@ mod tests {
             generics: vec![],
             fields: vec![
                 StructField {
-                    name: "user_name".to_string(), // Should become userName
+                    name: "userName".to_string(), // Already camelCased by the parser
                     ty: RustType::Primitive("String".to_string()),
                     has_explicit_rename: false,
                     use_optional: false,
+                    presence: FieldPresence::Always,
+                    flatten: false,
+                    docs: Default::default(),
                 },
                 StructField {
                     name: "API_KEY".to_string(), // Should succeed as API_KEY
                     ty: RustType::Primitive("String".to_string()),
                     has_explicit_rename: true, // Simulate #[serde(rename = "API_KEY")]
                     use_optional: false,
+                    presence: FieldPresence::Always,
+                    flatten: false,
+                    docs: Default::default(),
                 },
                 StructField {
                     name: "snake_case_kept".to_string(), // Should fail check if it was converted
                     ty: RustType::Primitive("bool".to_string()),
                     has_explicit_rename: true, // Simulate #[serde(rename = "snake_case_kept")]
                     use_optional: false,
+                    presence: FieldPresence::Always,
+                    flatten: false,
+                    docs: Default::default(),
                 },
             ],
             source_file: test_path(),
+            transparent: false,
+            kind: StructKind::Named,
+            docs: Default::default(),
+            event: None,
+            ts_name: None,
+            export: false,
         };
 
         let ctx = default_ctx();
@@ -624,6 +925,430 @@ mod tests {
         assert!(!output.contains("snakeCaseKept: boolean"));
     }
 
+    #[test]
+    fn test_transparent_struct_is_type_alias() {
+        let s = RustStruct {
+            name: "UserId".to_string(),
+            generics: vec![],
+            fields: vec![StructField {
+                name: "field0".to_string(),
+                ty: RustType::Primitive("u64".to_string()),
+                has_explicit_rename: false,
+                use_optional: false,
+                presence: FieldPresence::Always,
+                flatten: false,
+                docs: Default::default(),
+            }],
+            source_file: test_path(),
+            transparent: true,
+            kind: StructKind::Tuple,
+            docs: Default::default(),
+            event: None,
+            ts_name: None,
+            export: false,
+        };
+
+        let output = generate_interface(&s, &default_ctx());
+        assert_eq!(output, "export type UserId = number;\n");
+    }
+
+    fn tuple_field(index: usize, ty: RustType) -> StructField {
+        StructField {
+            name: format!("field{}", index),
+            ty,
+            has_explicit_rename: false,
+            use_optional: false,
+            presence: FieldPresence::Always,
+            flatten: false,
+            docs: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_generate_type_alias() {
+        let mut ctx = default_ctx();
+        ctx.register_type("User");
+        ctx.register_type("Paginated");
+
+        let list = RustTypeAlias {
+            name: "UserList".to_string(),
+            generics: vec![],
+            target: RustType::Vec(Box::new(RustType::Custom("User".to_string()))),
+            source_file: test_path(),
+            docs: Default::default(),
+        };
+        assert_eq!(generate_type_alias(&list, &ctx), "export type UserList = User[];\n");
+
+        let page = RustTypeAlias {
+            name: "Page".to_string(),
+            generics: vec!["T".to_string()],
+            target: RustType::CustomGeneric {
+                name: "Paginated".to_string(),
+                args: vec![RustType::Generic("T".to_string())],
+            },
+            source_file: test_path(),
+            docs: Default::default(),
+        };
+        assert_eq!(generate_type_alias(&page, &ctx), "export type Page<T> = Paginated<T>;\n");
+    }
+
+    #[test]
+    fn test_newtype_struct_is_inner_type() {
+        let s = RustStruct {
+            name: "UserId".to_string(),
+            generics: vec![],
+            fields: vec![tuple_field(0, RustType::Primitive("u64".to_string()))],
+            source_file: test_path(),
+            transparent: false,
+            kind: StructKind::Tuple,
+            docs: Default::default(),
+            event: None,
+            ts_name: None,
+            export: false,
+        };
+
+        let output = generate_interface(&s, &default_ctx());
+        assert_eq!(output, "export type UserId = number;\n");
+    }
+
+    #[test]
+    fn test_tuple_struct_is_ts_tuple() {
+        let s = RustStruct {
+            name: "Pair".to_string(),
+            generics: vec!["T".to_string()],
+            fields: vec![
+                tuple_field(0, RustType::Primitive("f64".to_string())),
+                tuple_field(1, RustType::Generic("T".to_string())),
+            ],
+            source_file: test_path(),
+            transparent: false,
+            kind: StructKind::Tuple,
+            docs: Default::default(),
+            event: None,
+            ts_name: None,
+            export: false,
+        };
+
+        let output = generate_interface(&s, &default_ctx());
+        assert_eq!(output, "export type Pair<T> = [number, T];\n");
+    }
+
+    #[test]
+    fn test_tuple_struct_with_skipped_field_stays_tuple() {
+        let mut skipped = tuple_field(0, RustType::Primitive("u32".to_string()));
+        skipped.presence = FieldPresence::Skipped;
+        let s = RustStruct {
+            name: "Pair".to_string(),
+            generics: vec![],
+            fields: vec![skipped, tuple_field(1, RustType::Primitive("String".to_string()))],
+            source_file: test_path(),
+            transparent: false,
+            kind: StructKind::Tuple,
+            docs: Default::default(),
+            event: None,
+            ts_name: None,
+            export: false,
+        };
+
+        let output = generate_interface(&s, &default_ctx());
+        assert_eq!(output, "export type Pair = [string];\n");
+    }
+
+    #[test]
+    fn test_unit_struct_is_null() {
+        let s = RustStruct {
+            name: "Marker".to_string(),
+            generics: vec![],
+            fields: vec![],
+            source_file: test_path(),
+            transparent: false,
+            kind: StructKind::Unit,
+            docs: Default::default(),
+            event: None,
+            ts_name: None,
+            export: false,
+        };
+
+        let output = generate_interface(&s, &default_ctx());
+        assert_eq!(output, "export type Marker = null;\n");
+    }
+
+    #[test]
+    fn test_default_fields_are_optional() {
+        let s = RustStruct {
+            name: "Settings".to_string(),
+            generics: vec![],
+            fields: vec![
+                StructField {
+                    name: "volume".to_string(),
+                    ty: RustType::Primitive("f32".to_string()),
+                    has_explicit_rename: false,
+                    use_optional: false,
+                    presence: FieldPresence::Optional,
+                    flatten: false,
+                    docs: Default::default(),
+                },
+                StructField {
+                    name: "theme".to_string(),
+                    ty: RustType::Option(Box::new(RustType::Primitive("String".to_string()))),
+                    has_explicit_rename: false,
+                    use_optional: false,
+                    presence: FieldPresence::Optional,
+                    flatten: false,
+                    docs: Default::default(),
+                },
+            ],
+            source_file: test_path(),
+            transparent: false,
+            kind: StructKind::Named,
+            docs: Default::default(),
+            event: None,
+            ts_name: None,
+            export: false,
+        };
+
+        let output = generate_interface(&s, &default_ctx());
+        assert!(output.contains("volume?: number;"));
+        assert!(output.contains("theme?: string | null;"));
+    }
+
+    #[test]
+    fn test_skipped_fields_are_dropped() {
+        let s = RustStruct {
+            name: "Account".to_string(),
+            generics: vec![],
+            fields: vec![
+                StructField {
+                    name: "id".to_string(),
+                    ty: RustType::Primitive("i32".to_string()),
+                    has_explicit_rename: false,
+                    use_optional: false,
+                    presence: FieldPresence::Always,
+                    flatten: false,
+                    docs: Default::default(),
+                },
+                StructField {
+                    name: "cache".to_string(),
+                    ty: RustType::Vec(Box::new(RustType::Primitive("u8".to_string()))),
+                    has_explicit_rename: false,
+                    use_optional: false,
+                    presence: FieldPresence::Skipped,
+                    flatten: false,
+                    docs: Default::default(),
+                },
+                StructField {
+                    name: "nickname".to_string(),
+                    ty: RustType::Option(Box::new(RustType::Primitive("String".to_string()))),
+                    has_explicit_rename: false,
+                    use_optional: true, // skip_serializing_if = "Option::is_none"
+                    presence: FieldPresence::Optional,
+                    flatten: false,
+                    docs: Default::default(),
+                },
+            ],
+            source_file: test_path(),
+            transparent: false,
+            kind: StructKind::Named,
+            docs: Default::default(),
+            event: None,
+            ts_name: None,
+            export: false,
+        };
+
+        let output = generate_interface(&s, &default_ctx());
+        assert!(output.contains("id: number;"));
+        assert!(!output.contains("cache"));
+        assert!(output.contains("nickname?: string;"));
+        assert!(!output.contains("null"));
+    }
+
+    fn flattened_field(name: &str, ty: RustType) -> StructField {
+        StructField {
+            name: name.to_string(),
+            ty,
+            has_explicit_rename: false,
+            use_optional: false,
+            presence: FieldPresence::Always,
+            flatten: true,
+            docs: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_flattened_fields_become_intersection() {
+        let mut ctx = default_ctx();
+        ctx.register_type("Metadata");
+
+        let s = RustStruct {
+            name: "Item".to_string(),
+            generics: vec![],
+            fields: vec![
+                StructField {
+                    name: "id".to_string(),
+                    ty: RustType::Primitive("i32".to_string()),
+                    has_explicit_rename: false,
+                    use_optional: false,
+                    presence: FieldPresence::Always,
+                    flatten: false,
+                    docs: Default::default(),
+                },
+                flattened_field("meta", RustType::Custom("Metadata".to_string())),
+                flattened_field(
+                    "extra",
+                    RustType::HashMap {
+                        key: Box::new(RustType::Primitive("String".to_string())),
+                        value: Box::new(RustType::Primitive("i32".to_string())),
+                    },
+                ),
+            ],
+            source_file: test_path(),
+            transparent: false,
+            kind: StructKind::Named,
+            docs: Default::default(),
+            event: None,
+            ts_name: None,
+            export: false,
+        };
+
+        let output = generate_interface(&s, &ctx);
+        assert_eq!(
+            output,
+            "export type Item = {\n  id: number;\n} & Metadata & Record<string, unknown>;\n"
+        );
+    }
+
+    #[test]
+    fn test_flattened_map_value_type_must_fit_siblings() {
+        let string_map = RustType::HashMap {
+            key: Box::new(RustType::Primitive("String".to_string())),
+            value: Box::new(RustType::Primitive("String".to_string())),
+        };
+        let mut s = RustStruct {
+            name: "Labels".to_string(),
+            generics: vec![],
+            fields: vec![
+                StructField {
+                    name: "name".to_string(),
+                    ty: RustType::Primitive("String".to_string()),
+                    has_explicit_rename: false,
+                    use_optional: false,
+                    presence: FieldPresence::Always,
+                    flatten: false,
+                    docs: Default::default(),
+                },
+                flattened_field("rest", string_map),
+            ],
+            source_file: test_path(),
+            transparent: false,
+            kind: StructKind::Named,
+            docs: Default::default(),
+            event: None,
+            ts_name: None,
+            export: false,
+        };
+
+        let output = generate_interface(&s, &default_ctx());
+        assert_eq!(
+            output,
+            "export type Labels = {\n  name: string;\n} & Record<string, string>;\n"
+        );
+
+        s.fields[0].ty = RustType::Primitive("u32".to_string());
+        let output = generate_interface(&s, &default_ctx());
+        assert_eq!(
+            output,
+            "export type Labels = {\n  name: number;\n} & Record<string, unknown>;\n"
+        );
+    }
+
+    #[test]
+    fn test_only_flattened_fields() {
+        let mut ctx = default_ctx();
+        ctx.register_type("Pagination");
+
+        let s = RustStruct {
+            name: "Page".to_string(),
+            generics: vec![],
+            fields: vec![flattened_field(
+                "pagination",
+                RustType::Option(Box::new(RustType::Custom("Pagination".to_string()))),
+            )],
+            source_file: test_path(),
+            transparent: false,
+            kind: StructKind::Named,
+            docs: Default::default(),
+            event: None,
+            ts_name: None,
+            export: false,
+        };
+
+        let output = generate_interface(&s, &ctx);
+        assert_eq!(output, "export type Page = Partial<Pagination>;\n");
+    }
+
+    #[test]
+    fn test_flattened_field_in_struct_variant() {
+        let mut ctx = default_ctx();
+        ctx.register_type("Metadata");
+
+        let e = RustEnum {
+            name: "Event".to_string(),
+            generics: vec![],
+            variants: vec![EnumVariant {
+                name: "Created".to_string(),
+                data: VariantData::Struct(vec![
+                    StructField {
+                        name: "id".to_string(),
+                        ty: RustType::Primitive("i32".to_string()),
+                        has_explicit_rename: false,
+                        use_optional: false,
+                        presence: FieldPresence::Always,
+                        flatten: false,
+                        docs: Default::default(),
+                    },
+                    flattened_field("meta", RustType::Custom("Metadata".to_string())),
+                ]),
+                has_explicit_rename: false,
+                docs: Default::default(),
+            }],
+            source_file: test_path(),
+            representation: EnumRepresentation::Internal { tag: "type".to_string() },
+            docs: Default::default(),
+            event: None,
+            ts_name: None,
+            export: false,
+        };
+
+        let output = generate_enum_type(&e, &ctx);
+        assert!(output.contains("{ type: \"Created\", id: number } & Metadata"), "{}", output);
+    }
+
+    #[test]
+    fn test_struct_variant_with_only_flattened_fields() {
+        let mut ctx = default_ctx();
+        ctx.register_type("Settings");
+
+        let variant = EnumVariant {
+            name: "Configure".to_string(),
+            data: VariantData::Struct(vec![flattened_field(
+                "settings",
+                RustType::Custom("Settings".to_string()),
+            )]),
+            has_explicit_rename: false,
+            docs: Default::default(),
+        };
+
+        let internal = EnumRepresentation::Internal { tag: "type".to_string() };
+        assert_eq!(
+            generate_variant(&variant, &internal, &ctx),
+            "{ type: \"Configure\" } & Settings"
+        );
+        assert_eq!(
+            generate_variant(&variant, &EnumRepresentation::External, &ctx),
+            "{ Configure: Settings }"
+        );
+    }
+
     #[test]
     fn test_enum_variant_explicit_rename_skips_camel_case() {
         // Enum with mixed renamed and normal variants
@@ -635,15 +1360,21 @@ mod tests {
                     name: "Active".to_string(),
                     data: VariantData::Unit,
                     has_explicit_rename: false,
+                    docs: Default::default(),
                 },
                 EnumVariant {
                     name: "INACTIVE_STATE".to_string(),
                     data: VariantData::Unit,
                     has_explicit_rename: true, // Explicitly renamed
+                    docs: Default::default(),
                 },
             ],
             source_file: test_path(),
             representation: EnumRepresentation::default(),
+            docs: Default::default(),
+            event: None,
+            ts_name: None,
+            export: false,
         };
 
         let ctx = default_ctx();
@@ -662,22 +1393,33 @@ mod tests {
                 name: "Login".to_string(),
                 data: VariantData::Struct(vec![
                     StructField {
-                        name: "user_id".to_string(),
+                        name: "userId".to_string(),
                         ty: RustType::Primitive("i32".to_string()),
-                        has_explicit_rename: false, // Normal conversion -> userId
+                        has_explicit_rename: false, // Already camelCased by the parser
                         use_optional: false,
+                        presence: FieldPresence::Always,
+                        flatten: false,
+                        docs: Default::default(),
                     },
                     StructField {
                         name: "TIMESTAMP".to_string(),
                         ty: RustType::Primitive("i64".to_string()),
                         has_explicit_rename: true, // Kept as TIMESTAMP
                         use_optional: false,
+                        presence: FieldPresence::Always,
+                        flatten: false,
+                        docs: Default::default(),
                     },
                 ]),
                 has_explicit_rename: false,
+                docs: Default::default(),
             }],
             source_file: test_path(),
             representation: EnumRepresentation::default(), // External tagging
+            docs: Default::default(),
+            event: None,
+            ts_name: None,
+            export: false,
         };
 
         let ctx = default_ctx();
@@ -699,15 +1441,27 @@ mod tests {
                     ty: RustType::Option(Box::new(RustType::Primitive("f32".to_string()))),
                     has_explicit_rename: false,
                     use_optional: true,
+                    presence: FieldPresence::Always,
+                    flatten: false,
+                    docs: Default::default(),
                 },
                 StructField {
                     name: "name".to_string(),
                     ty: RustType::Option(Box::new(RustType::Primitive("String".to_string()))),
                     has_explicit_rename: false,
                     use_optional: false,
+                    presence: FieldPresence::Always,
+                    flatten: false,
+                    docs: Default::default(),
                 },
             ],
             source_file: test_path(),
+            transparent: false,
+            kind: StructKind::Named,
+            docs: Default::default(),
+            event: None,
+            ts_name: None,
+            export: false,
         };
 
         let ctx = default_ctx();
@@ -716,4 +1470,82 @@ mod tests {
         assert!(output.contains("volume?: number"));
         assert!(output.contains("name: string | null"));
     }
+
+    #[test]
+    fn test_jsdoc_on_interface_and_fields() {
+        let s = RustStruct {
+            name: "User".to_string(),
+            generics: vec![],
+            fields: vec![StructField {
+                has_explicit_rename: false,
+                use_optional: false,
+                name: "id".to_string(),
+                ty: RustType::Primitive("i32".to_string()),
+                presence: FieldPresence::Always,
+                flatten: false,
+                docs: Docs {
+                    lines: vec!["Unique ID".to_string()],
+                    deprecated: None,
+                },
+            }],
+            source_file: test_path(),
+            transparent: false,
+            kind: StructKind::Named,
+            docs: Docs {
+                lines: vec!["A registered user".to_string()],
+                deprecated: None,
+            },
+            event: None,
+            ts_name: None,
+            export: false,
+        };
+
+        let output = generate_interface(&s, &default_ctx());
+
+        assert_eq!(
+            output,
+            "/** A registered user */\nexport interface User {\n  /** Unique ID */\n  id: number;\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_jsdoc_on_enum_variants() {
+        let e = RustEnum {
+            name: "Status".to_string(),
+            generics: vec![],
+            variants: vec![
+                EnumVariant {
+                    has_explicit_rename: false,
+                    name: "Active".to_string(),
+                    data: VariantData::Unit,
+                    docs: Docs {
+                        lines: vec!["Everything is fine".to_string()],
+                        deprecated: None,
+                    },
+                },
+                EnumVariant {
+                    has_explicit_rename: false,
+                    name: "Legacy".to_string(),
+                    data: VariantData::Unit,
+                    docs: Docs {
+                        lines: vec![],
+                        deprecated: Some(String::new()),
+                    },
+                },
+            ],
+            source_file: test_path(),
+            representation: EnumRepresentation::External,
+            docs: Default::default(),
+            event: None,
+            ts_name: None,
+            export: false,
+        };
+
+        let output = generate_enum_type(&e, &default_ctx());
+
+        assert_eq!(
+            output,
+            "export type Status =\n  /** Everything is fine */\n  | \"Active\"\n  /**\n   * @deprecated\n   */\n  | \"Legacy\";\n"
+        );
+    }
 }
@@ -18,42 +18,94 @@ pub const FLOAT_TYPES: &[&str] = &["f32", "f64"];
 /// Boolean type
 pub const BOOL_TYPE: &str = "bool";
 
-/// External types that serialize to strings (chrono, time, uuid, etc.)
-pub const EXTERNAL_STRING_TYPES: &[&str] = &[
-    // chrono
-    "DateTime",
-    "NaiveDateTime",
-    "NaiveDate",
-    "NaiveTime",
-    // time crate
-    "OffsetDateTime",
-    "PrimitiveDateTime",
-    "Date",
-    "Time",
-    // uuid
-    "Uuid",
-    // decimal
-    "Decimal",
-    "BigDecimal",
+/// A type from std or a common crate with a fixed serde representation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KnownType {
+    /// Crate the type lives in (`std` also covers `core` and `alloc`)
+    pub krate: &'static str,
+    /// Type name; matched against the last segment of the type's path
+    pub name: &'static str,
+    /// TypeScript type of its serde representation
+    pub ts_type: &'static str,
+}
+
+const fn known(krate: &'static str, name: &'static str, ts_type: &'static str) -> KnownType {
+    KnownType { krate, name, ts_type }
+}
+
+/// `{ secs, nanos }`, as serde serializes `std::time::Duration`
+const DURATION_SHAPE: &str = "{ secs: number; nanos: number }";
+/// `{ secs_since_epoch, nanos_since_epoch }`, as serde serializes `std::time::SystemTime`
+const SYSTEM_TIME_SHAPE: &str = "{ secs_since_epoch: number; nanos_since_epoch: number }";
+
+/// Known types and their serde shapes
+///
+/// When only a type's name is known (no import to tell crates apart),
+/// the first entry with that name is used.
+pub const KNOWN_TYPES: &[KnownType] = &[
+    // std::time
+    known("std", "Duration", DURATION_SHAPE),
+    known("std", "SystemTime", SYSTEM_TIME_SHAPE),
     // std::path
-    "PathBuf",
-    "Path",
-    // network types
-    "IpAddr",
-    "Ipv4Addr",
-    "Ipv6Addr",
+    known("std", "PathBuf", "string"),
+    known("std", "Path", "string"),
+    // std::net
+    known("std", "IpAddr", "string"),
+    known("std", "Ipv4Addr", "string"),
+    known("std", "Ipv6Addr", "string"),
+    known("std", "SocketAddr", "string"),
+    known("std", "SocketAddrV4", "string"),
+    known("std", "SocketAddrV6", "string"),
+    // std::num
+    known("std", "NonZero", "number"),
+    known("std", "NonZeroU8", "number"),
+    known("std", "NonZeroU16", "number"),
+    known("std", "NonZeroU32", "number"),
+    known("std", "NonZeroU64", "number"),
+    known("std", "NonZeroU128", "number"),
+    known("std", "NonZeroUsize", "number"),
+    known("std", "NonZeroI8", "number"),
+    known("std", "NonZeroI16", "number"),
+    known("std", "NonZeroI32", "number"),
+    known("std", "NonZeroI64", "number"),
+    known("std", "NonZeroI128", "number"),
+    known("std", "NonZeroIsize", "number"),
+    // chrono (RFC 3339 / ISO 8601 strings)
+    known("chrono", "DateTime", "string"),
+    known("chrono", "NaiveDateTime", "string"),
+    known("chrono", "NaiveDate", "string"),
+    known("chrono", "NaiveTime", "string"),
+    known("chrono", "Weekday", "string"),
+    known("chrono", "Month", "string"),
+    // time (with the `serde-human-readable` feature)
+    known("time", "OffsetDateTime", "string"),
+    known("time", "PrimitiveDateTime", "string"),
+    known("time", "Date", "string"),
+    known("time", "Time", "string"),
+    // `"<seconds>.<nanoseconds>"`, e.g. "1.500000000"
+    known("time", "Duration", "string"),
+    // uuid
+    known("uuid", "Uuid", "string"),
+    // decimals
+    known("rust_decimal", "Decimal", "string"),
+    known("bigdecimal", "BigDecimal", "string"),
     // url
-    "Url",
+    known("url", "Url", "string"),
+    // serde_json::Value - any JSON value
+    known("serde_json", "Value", "unknown"),
+    // bytes
+    known("bytes", "Bytes", "number[]"),
+    known("bytes", "BytesMut", "number[]"),
 ];
 
-/// Types that serialize to numbers
-pub const EXTERNAL_NUMBER_TYPES: &[&str] = &["Duration"];
+/// `tauri::ipc::Channel<T>` - streams `T` values to the frontend
+pub const CHANNEL_TYPE: &str = "tauri::ipc::Channel";
 
-/// serde_json::Value - any JSON value
-pub const JSON_VALUE_TYPE: &str = "Value";
-
-/// Bytes type
-pub const BYTES_TYPE: &str = "Bytes";
+/// Wrapper types that serde serializes transparently as their inner type
+/// (smart pointers, copy-on-write, interior-mutability cells and integer wrappers)
+pub const TRANSPARENT_WRAPPER_TYPES: &[&str] = &[
+    "Box", "Arc", "Rc", "Cow", "Cell", "RefCell", "Wrapping", "Saturating",
+];
 
 /// Check if a type name is a known primitive type
 pub fn is_primitive_type(name: &str) -> bool {
@@ -64,17 +116,35 @@ pub fn is_primitive_type(name: &str) -> bool {
         || name == BOOL_TYPE
 }
 
-/// Check if a type name is a known external type (serializes to string)
-pub fn is_external_string_type(name: &str) -> bool {
-    EXTERNAL_STRING_TYPES.contains(&name)
+/// Check if a type name is one of [`KNOWN_TYPES`], whichever crate it comes from
+pub fn is_known_type_name(name: &str) -> bool {
+    KNOWN_TYPES.iter().any(|known| known.name == name)
 }
 
-/// Check if a type name is a known external type (serializes to number)
-pub fn is_external_number_type(name: &str) -> bool {
-    EXTERNAL_NUMBER_TYPES.contains(&name)
+/// Find the known type for a path (`chrono::NaiveDate`, `std::time::Duration`, or just `Duration`)
+///
+/// A qualified path must come from the type's crate; a bare name picks the first entry with that name.
+pub fn lookup_known_type(path: &str) -> Option<&'static KnownType> {
+    let segments: Vec<&str> = path.trim_start_matches("::").split("::").collect();
+    let name = *segments.last()?;
+    let krate = match segments.first() {
+        _ if segments.len() == 1 => None,
+        Some(&("core" | "alloc")) => Some("std"),
+        Some(krate) => Some(*krate),
+        None => None,
+    };
+
+    KNOWN_TYPES
+        .iter()
+        .find(|known| known.name == name && krate.is_none_or(|krate| krate == known.krate))
 }
 
-/// Get the TypeScript type for a primitive Rust type name
+/// Check if a type name is a transparent wrapper (Box<T>, Arc<T>, Cow<'a, T>, ...)
+pub fn is_transparent_wrapper(name: &str) -> bool {
+    TRANSPARENT_WRAPPER_TYPES.contains(&name)
+}
+
+/// Get the TypeScript type for a primitive or known Rust type (name or path)
 pub fn primitive_to_typescript(name: &str) -> Option<&'static str> {
     if PRIMITIVE_STRING_TYPES.contains(&name) {
         return Some("string");
@@ -88,27 +158,83 @@ pub fn primitive_to_typescript(name: &str) -> Option<&'static str> {
     if name == BOOL_TYPE {
         return Some("boolean");
     }
-    if EXTERNAL_STRING_TYPES.contains(&name) {
-        return Some("string");
-    }
-    if EXTERNAL_NUMBER_TYPES.contains(&name) {
-        return Some("number");
-    }
-    if name == JSON_VALUE_TYPE {
-        return Some("unknown");
-    }
-    if name == BYTES_TYPE {
-        return Some("number[]");
-    }
-    None
+    lookup_known_type(name).map(|known| known.ts_type)
+}
+
+/// Tauri types that are injected by the framework and not passed from frontend
+/// (fully qualified, including the paths they are re-exported under)
+pub const TAURI_INJECTED_TYPES: &[&str] = &[
+    "tauri::State",
+    "tauri::AppHandle",
+    "tauri::Window",
+    "tauri::window::Window",
+    "tauri::Webview",
+    "tauri::webview::Webview",
+    "tauri::WebviewWindow",
+    "tauri::webview::WebviewWindow",
+    "tauri::ipc::Request",
+    "tauri::ipc::CommandScope",
+    "tauri::ipc::GlobalScope",
+    "tauri::ipc::InvokeMessage",
+];
+
+/// Raw request whose body reaches the command as bytes instead of JSON arguments
+pub const RAW_REQUEST_TYPE: &str = "tauri::ipc::Request";
+
+/// Raw responses, delivered to the frontend as an `ArrayBuffer`
+pub const RAW_RESPONSE_TYPES: &[&str] = &["tauri::ipc::Response", "tauri::ipc::InvokeResponseBody"];
+
+/// Check if a type is a raw IPC request (see [`is_injected_type`] for how `path` is matched)
+pub fn is_raw_request_type(path: &[String]) -> bool {
+    type_path_matches(path, RAW_REQUEST_TYPE)
 }
 
-/// Tauri special types that are injected by the framework and not passed from frontend
-pub const TAURI_SPECIAL_TYPES: &[&str] = &["State", "Window", "AppHandle", "Webview", "WebviewWindow"];
+/// Check if a type is a raw IPC response (see [`is_injected_type`] for how `path` is matched)
+pub fn is_raw_response_type(path: &[String]) -> bool {
+    RAW_RESPONSE_TYPES.iter().any(|entry| type_path_matches(path, entry))
+}
 
-/// Check if a type name is a Tauri special type (injected by framework)
-pub fn is_tauri_special_type(name: &str) -> bool {
-    TAURI_SPECIAL_TYPES.contains(&name)
+/// Check if a type is a Tauri IPC channel (see [`is_injected_type`] for how `path` is matched)
+pub fn is_channel_type(path: &[String]) -> bool {
+    type_path_matches(path, CHANNEL_TYPE)
+}
+
+/// Check if a type is injected by the framework, either a Tauri type or one of `extra`
+///
+/// `path` is the type's path as far as it could be qualified (e.g. `["tauri", "State"]`,
+/// or just `["State"]` when its origin is unknown).
+pub fn is_injected_type(path: &[String], extra: &[String]) -> bool {
+    TAURI_INJECTED_TYPES
+        .iter()
+        .copied()
+        .chain(extra.iter().map(String::as_str))
+        .any(|entry| type_path_matches(path, entry))
+}
+
+/// Find the `[types]` config entry for a type path, preferring an exact path over a bare name
+///
+/// Keys are matched like injected types (see [`is_injected_type`]).
+pub fn find_type_mapping<'a>(path: &[String], keys: impl IntoIterator<Item = &'a String>) -> Option<&'a String> {
+    let full_path = path.join("::");
+    let mut matching = keys.into_iter().filter(|key| type_path_matches(path, key)).peekable();
+    let first = *matching.peek()?;
+    Some(
+        matching
+            .find(|key| key.trim_start_matches("::") == full_path)
+            .unwrap_or(first),
+    )
+}
+
+/// Match a type path against an injected type entry
+///
+/// A bare entry (`DbConn`) matches any type with that name. A qualified entry
+/// (`crate::db::DbConn`) must match the full path, unless only the type's name is known.
+fn type_path_matches(path: &[String], entry: &str) -> bool {
+    let entry: Vec<&str> = entry.trim_start_matches("::").split("::").collect();
+    match (path, entry.as_slice()) {
+        (_, [name]) | ([_], [.., name]) => path.last().is_some_and(|last| last == name),
+        _ => path.iter().map(String::as_str).eq(entry.iter().copied()),
+    }
 }
 
 #[cfg(test)]
@@ -127,12 +253,35 @@ mod tests {
     }
 
     #[test]
-    fn test_is_external_string_type() {
-        assert!(is_external_string_type("DateTime"));
-        assert!(is_external_string_type("Uuid"));
-        assert!(is_external_string_type("PathBuf"));
-        assert!(!is_external_string_type("String"));
-        assert!(!is_external_string_type("User"));
+    fn test_is_known_type_name() {
+        assert!(is_known_type_name("DateTime"));
+        assert!(is_known_type_name("Uuid"));
+        assert!(is_known_type_name("PathBuf"));
+        assert!(is_known_type_name("NonZeroU32"));
+        assert!(!is_known_type_name("String"));
+        assert!(!is_known_type_name("User"));
+    }
+
+    #[test]
+    fn test_lookup_known_type_by_path() {
+        assert_eq!(lookup_known_type("std::time::Duration").unwrap().ts_type, DURATION_SHAPE);
+        assert_eq!(lookup_known_type("core::time::Duration").unwrap().ts_type, DURATION_SHAPE);
+        assert_eq!(lookup_known_type("Duration").unwrap().ts_type, DURATION_SHAPE);
+        assert_eq!(lookup_known_type("time::Date").unwrap().krate, "time");
+        assert_eq!(lookup_known_type("time::Duration").unwrap().ts_type, "string");
+        assert_eq!(lookup_known_type("chrono::naive::NaiveDate").unwrap().krate, "chrono");
+        // Same name, different crate
+        assert_eq!(lookup_known_type("chrono::Date"), None);
+        assert_eq!(lookup_known_type("crate::models::Duration"), None);
+    }
+
+    #[test]
+    fn test_is_transparent_wrapper() {
+        assert!(is_transparent_wrapper("Box"));
+        assert!(is_transparent_wrapper("Arc"));
+        assert!(is_transparent_wrapper("Cow"));
+        assert!(!is_transparent_wrapper("Mutex"));
+        assert!(!is_transparent_wrapper("Vec"));
     }
 
     #[test]
@@ -144,9 +293,69 @@ mod tests {
         assert_eq!(primitive_to_typescript("f64"), Some("number"));
         assert_eq!(primitive_to_typescript("bool"), Some("boolean"));
         assert_eq!(primitive_to_typescript("DateTime"), Some("string"));
-        assert_eq!(primitive_to_typescript("Duration"), Some("number"));
+        assert_eq!(primitive_to_typescript("Duration"), Some(DURATION_SHAPE));
+        assert_eq!(primitive_to_typescript("std::time::SystemTime"), Some(SYSTEM_TIME_SHAPE));
+        assert_eq!(primitive_to_typescript("NonZeroU64"), Some("number"));
         assert_eq!(primitive_to_typescript("Value"), Some("unknown"));
         assert_eq!(primitive_to_typescript("Bytes"), Some("number[]"));
         assert_eq!(primitive_to_typescript("User"), None);
     }
+
+    fn path(p: &str) -> Vec<String> {
+        p.split("::").map(String::from).collect()
+    }
+
+    #[test]
+    fn test_is_injected_type() {
+        assert!(is_injected_type(&path("tauri::State"), &[]));
+        assert!(is_injected_type(&path("tauri::ipc::Request"), &[]));
+        assert!(is_injected_type(&path("tauri::webview::WebviewWindow"), &[]));
+        // Only the name is known
+        assert!(is_injected_type(&path("AppHandle"), &[]));
+        assert!(is_injected_type(&path("CommandScope"), &[]));
+        // A project type that happens to share a Tauri name
+        assert!(!is_injected_type(&path("crate::api::Request"), &[]));
+        assert!(!is_injected_type(&path("crate::User"), &[]));
+    }
+
+    #[test]
+    fn test_is_injected_type_extra() {
+        let extra = vec!["crate::db::DbConn".to_string(), "Session".to_string()];
+        assert!(is_injected_type(&path("crate::db::DbConn"), &extra));
+        assert!(is_injected_type(&path("DbConn"), &extra));
+        assert!(!is_injected_type(&path("crate::other::DbConn"), &extra));
+        assert!(is_injected_type(&path("crate::auth::Session"), &extra));
+        assert!(!is_injected_type(&path("crate::auth::User"), &extra));
+    }
+
+    #[test]
+    fn test_raw_ipc_types() {
+        assert!(is_raw_request_type(&path("tauri::ipc::Request")));
+        assert!(!is_raw_request_type(&path("crate::api::Request")));
+        assert!(is_raw_response_type(&path("tauri::ipc::Response")));
+        assert!(is_raw_response_type(&path("InvokeResponseBody")));
+        assert!(!is_raw_response_type(&path("crate::api::Response")));
+    }
+
+    #[test]
+    fn test_is_channel_type() {
+        assert!(is_channel_type(&path("tauri::ipc::Channel")));
+        assert!(is_channel_type(&path("Channel")));
+        assert!(!is_channel_type(&path("crate::stream::Channel")));
+        assert!(!is_channel_type(&path("tokio::sync::mpsc::Channel")));
+    }
+
+    #[test]
+    fn test_find_type_mapping() {
+        let keys = vec![
+            "Ulid".to_string(),
+            "crate::money::Money".to_string(),
+            "ulid::Ulid".to_string(),
+        ];
+        assert_eq!(find_type_mapping(&path("ulid::Ulid"), &keys).unwrap(), "ulid::Ulid");
+        assert_eq!(find_type_mapping(&path("other::Ulid"), &keys).unwrap(), "Ulid");
+        assert_eq!(find_type_mapping(&path("crate::money::Money"), &keys).unwrap(), "crate::money::Money");
+        assert_eq!(find_type_mapping(&path("crate::bank::Money"), &keys), None);
+        assert_eq!(find_type_mapping(&path("crate::User"), &keys), None);
+    }
 }
@@ -43,8 +43,9 @@ pub mod pipeline;
 pub mod resolver;
 pub mod scanner;
 pub mod utils;
+pub mod watch;
 
-// Re-export the TS derive macro for user convenience
-pub use tauri_ts_generator_derive::TS;
+// Re-export the TS derive and the command attribute macro for user convenience
+pub use tauri_ts_generator_derive::{ts, TS};
 
 
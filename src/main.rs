@@ -2,6 +2,7 @@ use anyhow::Result;
 use tauri_ts_generator::cli::{Cli, Commands};
 use tauri_ts_generator::config::Config;
 use tauri_ts_generator::pipeline::Pipeline;
+use tauri_ts_generator::watch::watch;
 
 fn main() -> Result<()> {
     let cli = Cli::parse_args();
@@ -10,6 +11,12 @@ fn main() -> Result<()> {
         Commands::Generate { config, verbose } => {
             run_generate(&config, verbose)?;
         }
+        Commands::Check { config, verbose } => {
+            run_check(&config, verbose)?;
+        }
+        Commands::Watch { config, verbose } => {
+            watch(&config, verbose)?;
+        }
         Commands::Init { output, force } => {
             run_init(&output, force)?;
         }
@@ -30,6 +37,28 @@ fn run_generate(config_path: &std::path::Path, verbose: bool) -> Result<()> {
     pipeline.run(&config)
 }
 
+/// Run the check command
+fn run_check(config_path: &std::path::Path, verbose: bool) -> Result<()> {
+    let config = Config::load(config_path)?;
+
+    if verbose {
+        println!("Loaded configuration from: {}", config_path.display());
+    }
+
+    let pipeline = Pipeline::new(verbose);
+    let stale = pipeline.check(&config)?;
+
+    if !stale.is_empty() {
+        anyhow::bail!(
+            "{} generated file(s) are out of date. Run `tauri-ts-generator generate` to update them.",
+            stale.len()
+        );
+    }
+
+    println!("Generated files are up to date.");
+    Ok(())
+}
+
 /// Run the init command
 fn run_init(output_path: &std::path::Path, force: bool) -> Result<()> {
     if output_path.exists() && !force {
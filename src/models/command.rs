@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-use super::RustType;
+use super::{Docs, RustType};
 
 /// Represents a parsed Tauri command
 #[derive(Debug, Clone)]
@@ -16,6 +16,16 @@ pub struct TauriCommand {
     /// Value of rename_all attribute from #[tauri::command(rename_all = "...")]
     /// Defaults to "camelCase" behavior when None
     pub rename_all: Option<String>,
+    /// Doc comments and deprecation
+    pub docs: Docs,
+    /// Name of the Tauri plugin exposing this command; invoked as `plugin:<name>|<command>`
+    pub plugin: Option<String>,
+    /// Whether the command reads the raw `tauri::ipc::Request` body instead of JSON arguments
+    pub raw_body: bool,
+    /// TypeScript function name from `#[ts(rename = "...")]`, used as is
+    pub ts_name: Option<String>,
+    /// Casing of the TypeScript parameter names from `#[ts(rename_all = "...")]` (camelCase when None)
+    pub ts_rename_all: Option<String>,
 }
 
 /// Represents a function argument
@@ -25,5 +35,17 @@ pub struct CommandArg {
     pub name: String,
     /// Argument type
     pub ty: RustType,
+    /// Description from the `# Arguments` section of the command's doc comment
+    pub docs: Docs,
 }
 
+/// A command path listed in a `tauri::generate_handler![...]` invocation
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegisteredCommand {
+    /// Path segments as written (e.g. `["commands", "user", "get_user"]`)
+    pub path: Vec<String>,
+    /// Source file containing the invocation
+    pub source_file: PathBuf,
+    /// Plugin name if the handler belongs to `tauri::plugin::Builder::new("<name>")`
+    pub plugin: Option<String>,
+}
@@ -0,0 +1,17 @@
+/// Documentation attached to a command, type, field or variant
+///
+/// Captured from `///` doc comments and the `#[deprecated]` attribute.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Docs {
+    /// Doc comment lines, with the leading space of each `///` line removed
+    pub lines: Vec<String>,
+    /// Set if the item is marked #[deprecated]; contains the note (may be empty)
+    pub deprecated: Option<String>,
+}
+
+impl Docs {
+    /// Whether there is nothing to document
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty() && self.deprecated.is_none()
+    }
+}
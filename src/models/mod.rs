@@ -1,12 +1,17 @@
 //! Data models for representing Tauri commands and Rust types.
 
 mod command;
+mod docs;
 mod parse_result;
 mod rust_type;
 mod types;
 
-pub use command::{CommandArg, TauriCommand};
+pub use command::{CommandArg, RegisteredCommand, TauriCommand};
+pub use docs::Docs;
 pub use parse_result::ParseResult;
 pub use rust_type::RustType;
-pub use types::{EnumRepresentation, EnumVariant, RustEnum, RustStruct, StructField, VariantData};
+pub use types::{
+    EnumRepresentation, EnumVariant, FieldPresence, RustEnum, RustStruct, RustTypeAlias, StructField,
+    StructKind, VariantData,
+};
 
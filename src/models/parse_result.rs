@@ -1,4 +1,4 @@
-use super::{RustEnum, RustStruct, TauriCommand};
+use super::{RegisteredCommand, RustEnum, RustStruct, RustTypeAlias, TauriCommand};
 
 /// Result of parsing a Rust file
 #[derive(Debug, Default)]
@@ -9,6 +9,10 @@ pub struct ParseResult {
     pub structs: Vec<RustStruct>,
     /// Enums found in the file
     pub enums: Vec<RustEnum>,
+    /// Type aliases found in the file
+    pub type_aliases: Vec<RustTypeAlias>,
+    /// Commands listed in `generate_handler!` invocations
+    pub registered_commands: Vec<RegisteredCommand>,
 }
 
 impl ParseResult {
@@ -3,21 +3,43 @@
 pub enum RustType {
     /// Primitive types (String, i32, bool, etc.)
     Primitive(String),
-    /// `Vec<T>`
+    /// `Vec<T>` and other sequences (`VecDeque<T>`, `HashSet<T>`, `BTreeSet<T>`, ...)
     Vec(Box<RustType>),
+    /// Fixed-size array `[T; N]`
+    Array {
+        elem: Box<RustType>,
+        len: usize,
+    },
     /// `Option<T>`
     Option(Box<RustType>),
-    /// `Result<T, E>` - only Ok type is used for TypeScript generation
-    Result(Box<RustType>),
-    /// `HashMap<K, V>`
+    /// `Result<T, E>`
+    Result {
+        ok: Box<RustType>,
+        /// `None` when the error type isn't spelled out (e.g. a `Result<T>` alias)
+        err: Option<Box<RustType>>,
+    },
+    /// `HashMap<K, V>` and other maps (`BTreeMap<K, V>`, `IndexMap<K, V>`)
     HashMap {
         key: Box<RustType>,
         value: Box<RustType>,
     },
+    /// `tauri::ipc::Channel<T>` command argument, mapped to the frontend `Channel<T>` class
+    Channel(Box<RustType>),
+    /// Type mapped in the `[types]` config table, holding the entry's key
+    Mapped(String),
+    /// TypeScript type given verbatim with `#[ts(type = "...")]`
+    Verbatim(String),
+    /// Raw binary IPC response (`tauri::ipc::Response`), received by the frontend as an `ArrayBuffer`
+    Binary,
     /// Tuple types
     Tuple(Vec<RustType>),
     /// Reference to a custom type (struct or enum)
     Custom(String),
+    /// Reference to a generic custom type with its type arguments (e.g., `Page<User>`)
+    CustomGeneric {
+        name: String,
+        args: Vec<RustType>,
+    },
     /// Generic type parameter (T, U, K, V, etc.)
     Generic(String),
     /// Unit type ()
@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-use super::RustType;
+use super::{Docs, RustType};
 
 /// Represents a parsed Rust struct
 #[derive(Debug, Clone, PartialEq)]
@@ -13,21 +13,91 @@ pub struct RustStruct {
     pub fields: Vec<StructField>,
     /// Source file where the struct was found
     pub source_file: PathBuf,
+    /// Whether the struct is marked #[serde(transparent)]
+    /// If true, it serializes exactly like its single field
+    pub transparent: bool,
+    /// Shape of the struct (named fields, tuple, or unit)
+    pub kind: StructKind,
+    /// Doc comments and deprecation
+    pub docs: Docs,
+    /// Event name from `#[ts(event = "...")]` if this type is an event payload
+    pub event: Option<String>,
+    /// TypeScript name from `#[ts(rename = "...")]`, used as is instead of the formatted Rust name
+    pub ts_name: Option<String>,
+    /// Whether `#[ts(export)]` asks for the type even if no command uses it
+    pub export: bool,
+}
+
+/// Represents the shape of a struct, which determines how serde serializes it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StructKind {
+    /// struct User { id: u64 } -> { "id": 1 }
+    #[default]
+    Named,
+    /// struct UserId(u64) -> 1, struct Point(f64, f64) -> [1.0, 2.0]
+    /// Fields are named field0, field1, ...
+    Tuple,
+    /// struct Marker; -> null
+    Unit,
 }
 
 /// Represents a struct field
 #[derive(Debug, Clone, PartialEq)]
 pub struct StructField {
-    /// Field name (may be renamed via serde)
+    /// Field name as it appears in the serialized object
+    /// (after #[serde(rename)], #[serde(rename_all)] or the default camelCase conversion)
     pub name: String,
     /// Field type
     pub ty: RustType,
-    /// Whether the name was explicitly set via #[serde(rename = "...")]
-    /// If true, the name should be used as-is without camelCase conversion
+    /// Whether the name was dictated by serde (#[serde(rename = "...")] or a rename_all rule)
+    /// rather than the default camelCase conversion
     pub has_explicit_rename: bool,
     /// Whether to use undefined instead of null for Option types
     /// Set via #[ts(optional)] attribute
     pub use_optional: bool,
+    /// Whether the field is always present, may be missing, or is never serialized
+    pub presence: FieldPresence,
+    /// Whether the field is marked #[serde(flatten)]
+    /// If true, its contents are merged into the parent object instead of nested under `name`
+    pub flatten: bool,
+    /// Doc comments and deprecation
+    pub docs: Docs,
+}
+
+/// Describes whether a struct field appears in the serialized object
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FieldPresence {
+    /// Always present
+    #[default]
+    Always,
+    /// May be missing: #[serde(skip_serializing)], #[serde(skip_serializing_if = "...")]
+    Optional,
+    /// Always serialized, but may be left out when sent from the frontend: #[serde(default)]
+    DefaultOnInput,
+    /// Never present: #[serde(skip)]
+    Skipped,
+}
+
+impl StructField {
+    /// Whether the field appears in the serialized object at all
+    pub fn is_serialized(&self) -> bool {
+        self.presence != FieldPresence::Skipped
+    }
+}
+
+/// Represents a parsed Rust type alias (e.g., `type UserList = Vec<User>;`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct RustTypeAlias {
+    /// Name of the alias
+    pub name: String,
+    /// Generic type parameters (e.g., ["T"])
+    pub generics: Vec<String>,
+    /// The aliased type
+    pub target: RustType,
+    /// Source file where the alias was found
+    pub source_file: PathBuf,
+    /// Doc comments and deprecation
+    pub docs: Docs,
 }
 
 /// Represents a parsed Rust enum
@@ -43,6 +113,14 @@ pub struct RustEnum {
     pub source_file: PathBuf,
     /// Serde representation of the enum (External, Internal, Adjacent, Untagged)
     pub representation: EnumRepresentation,
+    /// Doc comments and deprecation
+    pub docs: Docs,
+    /// Event name from `#[ts(event = "...")]` if this type is an event payload
+    pub event: Option<String>,
+    /// TypeScript name from `#[ts(rename = "...")]`, used as is instead of the formatted Rust name
+    pub ts_name: Option<String>,
+    /// Whether `#[ts(export)]` asks for the type even if no command uses it
+    pub export: bool,
 }
 
 /// Represents the serde representation of an enum
@@ -69,6 +147,8 @@ pub struct EnumVariant {
     /// Whether the name was explicitly set via #[serde(rename = "...")]
     /// If true, the name should be used as-is without transformation
     pub has_explicit_rename: bool,
+    /// Doc comments and deprecation
+    pub docs: Docs,
 }
 
 /// Represents the data associated with an enum variant
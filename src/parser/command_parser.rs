@@ -1,8 +1,10 @@
-use crate::models::{CommandArg, RustType, TauriCommand};
+use crate::models::{CommandArg, Docs, RustType, TauriCommand};
 use anyhow::Result;
 use std::path::Path;
 use syn::{FnArg, ItemFn, ReturnType};
 
+use super::docs::{parse_docs, split_argument_docs};
+use super::ts_attrs::parse_ts_naming;
 use super::type_extractor::parse_type;
 
 /// Parse a Rust source file and extract Tauri commands
@@ -12,11 +14,9 @@ pub fn parse_commands(content: &str, source_file: &Path) -> Result<Vec<TauriComm
 
     for item in syntax.items {
         match item {
-            syn::Item::Fn(ref func) => {
-                if is_tauri_command(func) {
-                    if let Some(cmd) = parse_command_fn(func, source_file) {
-                        commands.push(cmd);
-                    }
+            syn::Item::Fn(ref func) if is_tauri_command(func) => {
+                if let Some(cmd) = parse_command_fn(func, source_file) {
+                    commands.push(cmd);
                 }
             }
             syn::Item::Impl(ref impl_block) => {
@@ -105,6 +105,10 @@ fn extract_rename_all(attrs: &[syn::Attribute]) -> Option<String> {
 
 /// Parse a function into a TauriCommand
 fn parse_command_fn(func: &ItemFn, source_file: &Path) -> Option<TauriCommand> {
+    let naming = parse_ts_naming(&func.attrs);
+    if naming.skip {
+        return None;
+    }
     let name = func.sig.ident.to_string();
 
     let args = func
@@ -116,6 +120,7 @@ fn parse_command_fn(func: &ItemFn, source_file: &Path) -> Option<TauriCommand> {
 
     let return_type = parse_return_type(&func.sig.output);
     let rename_all = extract_rename_all(&func.attrs);
+    let (docs, args) = parse_command_docs(&func.attrs, args);
 
     Some(TauriCommand {
         name,
@@ -123,11 +128,20 @@ fn parse_command_fn(func: &ItemFn, source_file: &Path) -> Option<TauriCommand> {
         return_type,
         source_file: source_file.to_path_buf(),
         rename_all,
+        docs,
+        plugin: None,
+        raw_body: false,
+        ts_name: naming.rename,
+        ts_rename_all: naming.rename_all,
     })
 }
 
 /// Parse a method into a TauriCommand
 fn parse_command_method(method: &syn::ImplItemFn, source_file: &Path) -> Option<TauriCommand> {
+    let naming = parse_ts_naming(&method.attrs);
+    if naming.skip {
+        return None;
+    }
     let name = method.sig.ident.to_string();
 
     let args = method
@@ -139,6 +153,7 @@ fn parse_command_method(method: &syn::ImplItemFn, source_file: &Path) -> Option<
 
     let return_type = parse_return_type(&method.sig.output);
     let rename_all = extract_rename_all(&method.attrs);
+    let (docs, args) = parse_command_docs(&method.attrs, args);
 
     Some(TauriCommand {
         name,
@@ -146,9 +161,28 @@ fn parse_command_method(method: &syn::ImplItemFn, source_file: &Path) -> Option<
         return_type,
         source_file: source_file.to_path_buf(),
         rename_all,
+        docs,
+        plugin: None,
+        raw_body: false,
+        ts_name: naming.rename,
+        ts_rename_all: naming.rename_all,
     })
 }
 
+/// Parse the command's docs, moving the `# Arguments` section onto the individual arguments
+fn parse_command_docs(attrs: &[syn::Attribute], mut args: Vec<CommandArg>) -> (Docs, Vec<CommandArg>) {
+    let mut docs = parse_docs(attrs);
+    let mut arg_docs = split_argument_docs(&mut docs);
+
+    for arg in &mut args {
+        if let Some(description) = arg_docs.remove(&arg.name) {
+            arg.docs.lines.push(description);
+        }
+    }
+
+    (docs, args)
+}
+
 /// Parse a function argument
 fn parse_fn_arg(arg: &FnArg) -> Option<CommandArg> {
     match arg {
@@ -161,7 +195,11 @@ fn parse_fn_arg(arg: &FnArg) -> Option<CommandArg> {
 
             let ty = parse_type(&pat_type.ty);
 
-            Some(CommandArg { name, ty })
+            Some(CommandArg {
+                name,
+                ty,
+                docs: Docs::default(),
+            })
         }
         FnArg::Receiver(_) => None, // Skip self arguments
     }
@@ -249,7 +287,7 @@ mod tests {
         assert!(commands[0].return_type.is_some());
 
         match &commands[0].return_type {
-            Some(RustType::Result(inner)) => match inner.as_ref() {
+            Some(RustType::Result { ok: inner, .. }) => match inner.as_ref() {
                 RustType::Custom(name) => assert_eq!(name, "User"),
                 other => panic!("Expected Custom, got {:?}", other),
             },
@@ -452,5 +490,69 @@ mod tests {
         assert_eq!(commands.len(), 1);
         assert_eq!(commands[0].rename_all, Some("snake_case".to_string()));
     }
-}
 
+    #[test]
+    fn test_parse_command_docs() {
+        let code = r#"
+            /// Fetch a user by ID.
+            ///
+            /// # Arguments
+            ///
+            /// * `user_id` - The user's ID
+            #[deprecated(note = "use get_user_v2")]
+            #[tauri::command]
+            fn get_user(user_id: i32, verbose: bool) {}
+        "#;
+
+        let commands = parse_commands(code, &test_path()).unwrap();
+        assert_eq!(commands[0].docs.lines, vec!["Fetch a user by ID."]);
+        assert_eq!(commands[0].docs.deprecated, Some("use get_user_v2".to_string()));
+        assert_eq!(commands[0].args[0].docs.lines, vec!["The user's ID"]);
+        assert!(commands[0].args[1].docs.is_empty());
+    }
+
+    #[test]
+    fn test_parse_ts_naming_on_commands() {
+        let code = r#"
+            #[tauri::command]
+            #[tauri_ts_generator::ts(rename = "fetchUser", rename_all = "snake_case")]
+            fn get_user(user_id: i32) {}
+
+            #[tauri::command]
+            #[ts(skip)]
+            fn debug_dump() {}
+
+            struct Api;
+
+            impl Api {
+                #[tauri::command]
+                #[tauri_ts_generator::ts(skip)]
+                fn internal(&self) {}
+            }
+        "#;
+
+        let commands = parse_commands(code, &test_path()).unwrap();
+        assert_eq!(commands.len(), 1);
+        assert_eq!(commands[0].name, "get_user");
+        assert_eq!(commands[0].ts_name.as_deref(), Some("fetchUser"));
+        assert_eq!(commands[0].ts_rename_all.as_deref(), Some("snake_case"));
+    }
+
+    #[test]
+    fn test_parse_ts_naming_derive_crate_path() {
+        let code = r#"
+            #[tauri::command]
+            #[tauri_ts_generator_derive::ts(rename = "fetchUser", rename_all = "snake_case")]
+            fn get_user(user_id: i32) {}
+
+            #[tauri::command]
+            #[tauri_ts_generator_derive::ts(skip)]
+            fn debug_dump() {}
+        "#;
+
+        let commands = parse_commands(code, &test_path()).unwrap();
+        assert_eq!(commands.len(), 1);
+        assert_eq!(commands[0].ts_name.as_deref(), Some("fetchUser"));
+        assert_eq!(commands[0].ts_rename_all.as_deref(), Some("snake_case"));
+    }
+}
@@ -0,0 +1,251 @@
+use crate::models::Docs;
+use std::collections::HashMap;
+use syn::{Attribute, Expr, Lit, Meta};
+
+/// Section headings in a command's doc comment that describe its arguments
+const ARGUMENT_SECTION_HEADINGS: &[&str] = &["# Arguments", "# Args", "# Parameters"];
+
+/// Extract doc comments (`///`, `/** */`, `#[doc = "..."]`) and `#[deprecated]` from attributes
+pub fn parse_docs(attrs: &[Attribute]) -> Docs {
+    let mut docs = Docs::default();
+
+    for attr in attrs {
+        if attr.path().is_ident("doc") {
+            if let Meta::NameValue(nv) = &attr.meta {
+                if let Some(value) = lit_str_value(&nv.value) {
+                    push_doc_lines(&mut docs.lines, &value);
+                }
+            }
+        } else if attr.path().is_ident("deprecated") {
+            docs.deprecated = Some(parse_deprecated_note(&attr.meta).unwrap_or_default());
+        }
+    }
+
+    // Drop blank lines at the start and end
+    while docs.lines.first().is_some_and(|l| l.trim().is_empty()) {
+        docs.lines.remove(0);
+    }
+    while docs.lines.last().is_some_and(|l| l.trim().is_empty()) {
+        docs.lines.pop();
+    }
+
+    docs
+}
+
+/// Remove the `# Arguments` section from a command's docs and return per-argument descriptions
+///
+/// Recognizes the rustdoc convention:
+/// ```text
+/// # Arguments
+///
+/// * `name` - Description that may continue
+///   on the next line
+/// ```
+pub fn split_argument_docs(docs: &mut Docs) -> HashMap<String, String> {
+    let mut args: HashMap<String, String> = HashMap::new();
+
+    let Some(start) = docs
+        .lines
+        .iter()
+        .position(|l| ARGUMENT_SECTION_HEADINGS.contains(&l.trim()))
+    else {
+        return args;
+    };
+
+    // The section ends at the next heading
+    let end = docs.lines[start + 1..]
+        .iter()
+        .position(|l| l.trim_start().starts_with('#'))
+        .map(|i| start + 1 + i)
+        .unwrap_or(docs.lines.len());
+
+    let mut current: Option<String> = None;
+    for line in &docs.lines[start + 1..end] {
+        let trimmed = line.trim();
+        if let Some(item) = trimmed.strip_prefix("* ").or_else(|| trimmed.strip_prefix("- ")) {
+            if let Some((name, description)) = parse_argument_item(item) {
+                args.insert(name.clone(), description);
+                current = Some(name);
+                continue;
+            }
+            current = None;
+        } else if trimmed.is_empty() {
+            current = None;
+        } else if let Some(name) = &current {
+            // Continuation of the previous argument's description
+            if let Some(description) = args.get_mut(name) {
+                description.push(' ');
+                description.push_str(trimmed);
+            }
+        }
+    }
+
+    docs.lines.drain(start..end);
+    while docs.lines.last().is_some_and(|l| l.trim().is_empty()) {
+        docs.lines.pop();
+    }
+
+    args
+}
+
+/// Parse "`name` - description" or "name: description"
+fn parse_argument_item(item: &str) -> Option<(String, String)> {
+    let (name, rest) = if let Some(rest) = item.strip_prefix('`') {
+        let end = rest.find('`')?;
+        (&rest[..end], &rest[end + 1..])
+    } else {
+        let end = item.find(|c: char| !(c.is_alphanumeric() || c == '_'))?;
+        (&item[..end], &item[end..])
+    };
+
+    let description = rest
+        .trim_start()
+        .trim_start_matches(['-', ':', '—'])
+        .trim();
+
+    if name.is_empty() {
+        None
+    } else {
+        Some((name.to_string(), description.to_string()))
+    }
+}
+
+/// Split a doc attribute value into lines
+fn push_doc_lines(lines: &mut Vec<String>, value: &str) {
+    // Block comments (/** ... */) arrive as a single multi-line value
+    let is_block = value.contains('\n');
+
+    // An empty `///` is a paragraph break
+    if value.trim().is_empty() && !is_block {
+        lines.push(String::new());
+        return;
+    }
+
+    for line in value.lines() {
+        let line = if is_block {
+            let trimmed = line.trim_start();
+            trimmed
+                .strip_prefix("* ")
+                .or_else(|| trimmed.strip_prefix('*'))
+                .unwrap_or(trimmed)
+        } else {
+            // `/// text` is stored as " text"
+            line.strip_prefix(' ').unwrap_or(line)
+        };
+        lines.push(line.trim_end().to_string());
+    }
+}
+
+/// Get the note of #[deprecated], #[deprecated = "..."] or #[deprecated(note = "...")]
+fn parse_deprecated_note(meta: &Meta) -> Option<String> {
+    match meta {
+        Meta::Path(_) => None,
+        Meta::NameValue(nv) => lit_str_value(&nv.value),
+        Meta::List(list) => {
+            let nested = list
+                .parse_args_with(syn::punctuated::Punctuated::<Meta, syn::Token![,]>::parse_terminated)
+                .ok()?;
+            nested.iter().find_map(|meta| match meta {
+                Meta::NameValue(nv) if nv.path.is_ident("note") => lit_str_value(&nv.value),
+                _ => None,
+            })
+        }
+    }
+}
+
+fn lit_str_value(expr: &Expr) -> Option<String> {
+    if let Expr::Lit(expr_lit) = expr {
+        if let Lit::Str(lit_str) = &expr_lit.lit {
+            return Some(lit_str.value());
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attrs_of(code: &str) -> Vec<Attribute> {
+        let item: syn::ItemFn = syn::parse_str(code).expect("Failed to parse item");
+        item.attrs
+    }
+
+    #[test]
+    fn test_parse_line_docs() {
+        let attrs = attrs_of(
+            r#"
+            /// Fetch a user.
+            ///
+            /// Returns `None` if missing.
+            fn f() {}
+            "#,
+        );
+        let docs = parse_docs(&attrs);
+        assert_eq!(docs.lines, vec!["Fetch a user.", "", "Returns `None` if missing."]);
+        assert!(docs.deprecated.is_none());
+    }
+
+    #[test]
+    fn test_parse_block_docs() {
+        let attrs = attrs_of(
+            r#"
+            /**
+             * Block documented.
+             * Second line.
+             */
+            fn f() {}
+            "#,
+        );
+        let docs = parse_docs(&attrs);
+        assert_eq!(docs.lines, vec!["Block documented.", "Second line."]);
+    }
+
+    #[test]
+    fn test_parse_deprecated_forms() {
+        let docs = parse_docs(&attrs_of("#[deprecated] fn f() {}"));
+        assert_eq!(docs.deprecated, Some(String::new()));
+
+        let docs = parse_docs(&attrs_of(r#"#[deprecated = "use g"] fn f() {}"#));
+        assert_eq!(docs.deprecated, Some("use g".to_string()));
+
+        let docs = parse_docs(&attrs_of(r#"#[deprecated(since = "1.0", note = "use h")] fn f() {}"#));
+        assert_eq!(docs.deprecated, Some("use h".to_string()));
+    }
+
+    #[test]
+    fn test_split_argument_docs() {
+        let attrs = attrs_of(
+            r#"
+            /// Create a user.
+            ///
+            /// # Arguments
+            ///
+            /// * `name` - Display name
+            ///   shown in the UI
+            /// * `age` - Age in years
+            ///
+            /// # Errors
+            ///
+            /// Fails if the name is taken.
+            fn f() {}
+            "#,
+        );
+        let mut docs = parse_docs(&attrs);
+        let args = split_argument_docs(&mut docs);
+
+        assert_eq!(args.get("name").unwrap(), "Display name shown in the UI");
+        assert_eq!(args.get("age").unwrap(), "Age in years");
+        assert_eq!(
+            docs.lines,
+            vec!["Create a user.", "", "# Errors", "", "Fails if the name is taken."]
+        );
+    }
+
+    #[test]
+    fn test_split_argument_docs_without_section() {
+        let mut docs = parse_docs(&attrs_of("/// Just text\nfn f() {}"));
+        assert!(split_argument_docs(&mut docs).is_empty());
+        assert_eq!(docs.lines, vec!["Just text"]);
+    }
+}
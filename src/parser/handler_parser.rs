@@ -0,0 +1,188 @@
+use crate::models::RegisteredCommand;
+use anyhow::Result;
+use std::path::Path;
+use syn::punctuated::Punctuated;
+use syn::visit::Visit;
+use syn::{Expr, ExprMethodCall, Lit, Macro, Token};
+
+/// Find the commands registered via `tauri::generate_handler![...]` in a source file
+///
+/// Handlers passed to a `tauri::plugin::Builder::new("<name>")` chain are tagged with the plugin name.
+pub fn parse_registered_commands(content: &str, source_file: &Path) -> Result<Vec<RegisteredCommand>> {
+    let syntax = syn::parse_file(content)?;
+
+    let mut visitor = HandlerVisitor {
+        source_file,
+        plugin: None,
+        commands: Vec::new(),
+    };
+    visitor.visit_file(&syntax);
+
+    Ok(visitor.commands)
+}
+
+/// Collects paths from every generate_handler! macro, wherever it appears
+struct HandlerVisitor<'a> {
+    source_file: &'a Path,
+    /// Plugin whose invoke_handler is currently being visited
+    plugin: Option<String>,
+    commands: Vec<RegisteredCommand>,
+}
+
+impl<'ast> Visit<'ast> for HandlerVisitor<'_> {
+    fn visit_macro(&mut self, mac: &'ast Macro) {
+        let is_handler = mac
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "generate_handler");
+
+        if is_handler {
+            if let Ok(paths) = mac.parse_body_with(Punctuated::<syn::Path, Token![,]>::parse_terminated) {
+                for path in paths {
+                    self.commands.push(RegisteredCommand {
+                        path: path.segments.iter().map(|s| s.ident.to_string()).collect(),
+                        source_file: self.source_file.to_path_buf(),
+                        plugin: self.plugin.clone(),
+                    });
+                }
+            }
+        }
+
+        syn::visit::visit_macro(self, mac);
+    }
+
+    fn visit_expr_method_call(&mut self, call: &'ast ExprMethodCall) {
+        let plugin = if call.method == "invoke_handler" {
+            plugin_builder_name(&call.receiver)
+        } else {
+            None
+        };
+
+        match plugin {
+            Some(name) => {
+                self.visit_expr(&call.receiver);
+                let outer = self.plugin.replace(name);
+                for arg in &call.args {
+                    self.visit_expr(arg);
+                }
+                self.plugin = outer;
+            }
+            None => syn::visit::visit_expr_method_call(self, call),
+        }
+    }
+}
+
+/// Find `Builder::new("<name>")` at the root of a method chain
+fn plugin_builder_name(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::MethodCall(call) => plugin_builder_name(&call.receiver),
+        Expr::Call(call) => {
+            let Expr::Path(func) = call.func.as_ref() else {
+                return None;
+            };
+            let segments: Vec<_> = func.path.segments.iter().map(|s| s.ident.to_string()).collect();
+            if !segments.ends_with(&["Builder".to_string(), "new".to_string()]) {
+                return None;
+            }
+            match call.args.first() {
+                Some(Expr::Lit(lit)) => match &lit.lit {
+                    Lit::Str(name) => Some(name.value()),
+                    _ => None,
+                },
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn test_path() -> PathBuf {
+        PathBuf::from("main.rs")
+    }
+
+    fn paths(commands: &[RegisteredCommand]) -> Vec<String> {
+        commands.iter().map(|c| c.path.join("::")).collect()
+    }
+
+    #[test]
+    fn test_parse_generate_handler_in_builder_chain() {
+        let code = r#"
+            pub fn run() {
+                tauri::Builder::default()
+                    .invoke_handler(tauri::generate_handler![
+                        greet,
+                        commands::user::get_user,
+                        crate::settings::save,
+                    ])
+                    .run(tauri::generate_context!())
+                    .expect("error while running tauri application");
+            }
+        "#;
+
+        let commands = parse_registered_commands(code, &test_path()).unwrap();
+        assert_eq!(
+            paths(&commands),
+            vec!["greet", "commands::user::get_user", "crate::settings::save"]
+        );
+        assert_eq!(commands[0].source_file, test_path());
+    }
+
+    #[test]
+    fn test_parse_short_generate_handler() {
+        let code = r#"
+            use tauri::generate_handler;
+
+            fn handlers() -> impl Fn(tauri::ipc::Invoke) -> bool {
+                generate_handler![ping]
+            }
+        "#;
+
+        let commands = parse_registered_commands(code, &test_path()).unwrap();
+        assert_eq!(paths(&commands), vec!["ping"]);
+    }
+
+    #[test]
+    fn test_no_generate_handler() {
+        let code = r#"
+            #[tauri::command]
+            fn greet() {}
+        "#;
+
+        assert!(parse_registered_commands(code, &test_path()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_plugin_handler() {
+        let code = r#"
+            pub fn init<R: Runtime>() -> TauriPlugin<R> {
+                Builder::new("storage")
+                    .invoke_handler(tauri::generate_handler![commands::get_item, commands::set_item])
+                    .setup(|app, _api| Ok(()))
+                    .build()
+            }
+
+            pub fn run() {
+                tauri::Builder::default()
+                    .plugin(init())
+                    .invoke_handler(tauri::generate_handler![greet])
+                    .run(tauri::generate_context!())
+                    .unwrap();
+            }
+        "#;
+
+        let commands = parse_registered_commands(code, &test_path()).unwrap();
+        assert_eq!(
+            paths(&commands),
+            vec!["commands::get_item", "commands::set_item", "greet"]
+        );
+        assert_eq!(commands[0].plugin.as_deref(), Some("storage"));
+        assert_eq!(commands[1].plugin.as_deref(), Some("storage"));
+        assert_eq!(commands[2].plugin, None);
+    }
+}
@@ -1,6 +1,10 @@
 pub mod command_parser;
+pub mod docs;
+pub mod handler_parser;
+pub mod ts_attrs;
 pub mod type_extractor;
 pub mod type_parser;
 
 pub use command_parser::parse_commands;
-pub use type_parser::{parse_types, parse_types_expanded};
+pub use handler_parser::parse_registered_commands;
+pub use type_parser::{parse_type_aliases, parse_types, parse_types_expanded};
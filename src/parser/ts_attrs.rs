@@ -0,0 +1,97 @@
+use syn::meta::ParseNestedMeta;
+use syn::{Attribute, Expr, LitStr};
+
+/// Naming options of a #[ts(...)] attribute, which take precedence over serde's
+#[derive(Debug, Default, PartialEq)]
+pub struct TsNaming {
+    /// `#[ts(skip)]`: leave the item out of the TypeScript output
+    pub skip: bool,
+    /// `#[ts(rename = "...")]`
+    pub rename: Option<String>,
+    /// `#[ts(rename_all = "...")]`
+    pub rename_all: Option<String>,
+}
+
+/// Parse the skip/rename/rename_all options of the #[ts(...)] attributes
+pub fn parse_ts_naming(attrs: &[Attribute]) -> TsNaming {
+    let mut naming = TsNaming::default();
+    for_each_ts_option(attrs, |meta| {
+        if meta.path.is_ident("skip") {
+            naming.skip = true;
+        } else if meta.path.is_ident("rename") {
+            naming.rename = Some(ts_option_str(meta)?.value());
+        } else if meta.path.is_ident("rename_all") {
+            naming.rename_all = Some(ts_option_str(meta)?.value());
+        }
+        Ok(())
+    });
+    naming
+}
+
+/// Run `f` on every option of the #[ts(...)] attributes, skipping the values `f` doesn't read
+///
+/// Uses `parse_nested_meta` rather than `Meta`, which can't parse keyword options like `type` and `as`.
+pub fn for_each_ts_option(attrs: &[Attribute], mut f: impl FnMut(&ParseNestedMeta) -> syn::Result<()>) {
+    for attr in attrs.iter().filter(|attr| is_ts_attr(attr)) {
+        let _ = attr.parse_nested_meta(|meta| {
+            f(&meta)?;
+            if meta.input.peek(syn::Token![=]) {
+                meta.value()?.parse::<Expr>()?;
+            }
+            Ok(())
+        });
+    }
+}
+
+/// Read the string value of a `key = "..."` option
+pub fn ts_option_str(meta: &ParseNestedMeta) -> syn::Result<LitStr> {
+    meta.value()?.parse()
+}
+
+/// Crates that export the `ts` attribute macro used on commands
+const TS_ATTR_CRATES: &[&str] = &["tauri_ts_generator", "tauri_ts_generator_derive"];
+
+/// `#[ts(...)]`, or `#[tauri_ts_generator::ts(...)]` / `#[tauri_ts_generator_derive::ts(...)]` as written on commands
+fn is_ts_attr(attr: &Attribute) -> bool {
+    let segments: Vec<_> = attr.path().segments.iter().map(|s| s.ident.to_string()).collect();
+    match segments.as_slice() {
+        [name] => name == "ts",
+        [krate, name] => name == "ts" && TS_ATTR_CRATES.contains(&krate.as_str()),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn test_parse_ts_naming() {
+        let attrs: Vec<Attribute> = vec![
+            parse_quote!(#[serde(rename = "wire")]),
+            parse_quote!(#[ts(type = "string", rename = "Name")]),
+            parse_quote!(#[ts(skip, rename_all = "snake_case")]),
+        ];
+        assert_eq!(
+            parse_ts_naming(&attrs),
+            TsNaming {
+                skip: true,
+                rename: Some("Name".to_string()),
+                rename_all: Some("snake_case".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_qualified_attribute() {
+        let attrs: Vec<Attribute> = vec![parse_quote!(#[tauri_ts_generator::ts(rename = "fetchUser")])];
+        assert_eq!(parse_ts_naming(&attrs).rename.as_deref(), Some("fetchUser"));
+
+        let attrs: Vec<Attribute> = vec![parse_quote!(#[tauri_ts_generator_derive::ts(skip)])];
+        assert!(parse_ts_naming(&attrs).skip);
+
+        let attrs: Vec<Attribute> = vec![parse_quote!(#[other::ts(rename = "fetchUser")])];
+        assert_eq!(parse_ts_naming(&attrs), TsNaming::default());
+    }
+}
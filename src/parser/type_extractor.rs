@@ -1,7 +1,4 @@
-use crate::known_types::{
-    is_external_number_type, is_external_string_type, is_primitive_type, BYTES_TYPE,
-    JSON_VALUE_TYPE,
-};
+use crate::known_types::{is_known_type_name, is_primitive_type, is_transparent_wrapper};
 use crate::models::RustType;
 use std::collections::HashSet;
 use syn::{GenericArgument, PathArguments, Type};
@@ -34,27 +31,27 @@ pub fn parse_type_with_context(ty: &Type, generic_params: &HashSet<String>) -> R
                     return RustType::Primitive(normalized);
                 }
 
-                // Check if it's a known external type that serializes to string
-                if is_external_string_type(&name) {
-                    return RustType::Primitive(name);
+                // Known std/ecosystem types keep the path as written, so the pipeline
+                // can tell same-named types apart through the file's imports. With type
+                // arguments (`DateTime<Utc>`) it may be a generic project type, so it's
+                // parsed as a custom type and the pipeline drops the arguments once the
+                // import confirms the known crate
+                if is_known_type_name(&name) && extract_type_generics(&segment.arguments).is_empty() {
+                    return RustType::Primitive(path_to_string(&type_path.path));
                 }
 
-                // Check if it's a known external type that serializes to number
-                if is_external_number_type(&name) {
-                    return RustType::Primitive(name);
-                }
-
-                // Special types
-                if name == JSON_VALUE_TYPE {
-                    return RustType::Primitive(name);
-                }
-                if name == BYTES_TYPE {
-                    return RustType::Primitive(name);
+                // Smart pointers and wrappers serialize as their inner type
+                // (lifetimes are skipped, so Cow<'a, str> unwraps to str)
+                if is_transparent_wrapper(&name) {
+                    if let Some(inner) = extract_type_generics(&segment.arguments).first() {
+                        return parse_type_with_context(inner, generic_params);
+                    }
                 }
 
                 // Generic container types
                 match name.as_str() {
-                    "Vec" => {
+                    // Sequences and sets all serialize as JSON arrays
+                    "Vec" | "VecDeque" | "LinkedList" | "HashSet" | "BTreeSet" | "IndexSet" => {
                         if let Some(inner) = extract_single_generic(&segment.arguments) {
                             RustType::Vec(Box::new(parse_type_with_context(&inner, generic_params)))
                         } else {
@@ -72,16 +69,21 @@ pub fn parse_type_with_context(ty: &Type, generic_params: &HashSet<String>) -> R
                         }
                     }
                     "Result" => {
-                        if let Some(inner) = extract_single_generic(&segment.arguments) {
-                            RustType::Result(Box::new(parse_type_with_context(
-                                &inner,
-                                generic_params,
-                            )))
+                        if let Some((ok, err)) = extract_two_generics(&segment.arguments) {
+                            RustType::Result {
+                                ok: Box::new(parse_type_with_context(&ok, generic_params)),
+                                err: Some(Box::new(parse_type_with_context(&err, generic_params))),
+                            }
+                        } else if let Some(ok) = extract_single_generic(&segment.arguments) {
+                            RustType::Result {
+                                ok: Box::new(parse_type_with_context(&ok, generic_params)),
+                                err: None,
+                            }
                         } else {
                             RustType::Unknown("Result<?>".to_string())
                         }
                     }
-                    "HashMap" | "BTreeMap" => {
+                    "HashMap" | "BTreeMap" | "IndexMap" => {
                         if let Some((key, value)) = extract_two_generics(&segment.arguments) {
                             RustType::HashMap {
                                 key: Box::new(parse_type_with_context(&key, generic_params)),
@@ -95,14 +97,22 @@ pub fn parse_type_with_context(ty: &Type, generic_params: &HashSet<String>) -> R
                     // Custom types (not a known generic param)
                     _ => {
                         // Reconstruct full path for custom types
-                        let full_name = type_path
-                            .path
-                            .segments
+                        let full_name = path_to_string(&type_path.path);
+
+                        // Keep type arguments of generic custom types (e.g., Page<User>)
+                        let args: Vec<RustType> = extract_type_generics(&segment.arguments)
                             .iter()
-                            .map(|s| s.ident.to_string())
-                            .collect::<Vec<_>>()
-                            .join("::");
-                        RustType::Custom(full_name)
+                            .map(|arg| parse_type_with_context(arg, generic_params))
+                            .collect();
+
+                        if args.is_empty() {
+                            RustType::Custom(full_name)
+                        } else {
+                            RustType::CustomGeneric {
+                                name: full_name,
+                                args,
+                            }
+                        }
                     }
                 }
             } else {
@@ -128,6 +138,15 @@ pub fn parse_type_with_context(ty: &Type, generic_params: &HashSet<String>) -> R
             parse_type_with_context(&reference.elem, generic_params)
         }
 
+        Type::Array(array) => {
+            let elem = Box::new(parse_type_with_context(&array.elem, generic_params));
+            match array_len(&array.len) {
+                Some(len) => RustType::Array { elem, len },
+                // Length given by a const or expression - fall back to a plain array
+                None => RustType::Vec(elem),
+            }
+        }
+
         Type::Slice(slice) => {
             // Treat slices like Vec
             RustType::Vec(Box::new(parse_type_with_context(
@@ -140,6 +159,25 @@ pub fn parse_type_with_context(ty: &Type, generic_params: &HashSet<String>) -> R
     }
 }
 
+/// Path segments joined with `::`, without generic arguments
+fn path_to_string(path: &syn::Path) -> String {
+    path.segments
+        .iter()
+        .map(|s| s.ident.to_string())
+        .collect::<Vec<_>>()
+        .join("::")
+}
+
+/// Get the length of a fixed-size array if it's an integer literal (`[u8; 32]`)
+fn array_len(len: &syn::Expr) -> Option<usize> {
+    if let syn::Expr::Lit(expr_lit) = len {
+        if let syn::Lit::Int(lit_int) = &expr_lit.lit {
+            return lit_int.base10_parse().ok();
+        }
+    }
+    None
+}
+
 /// Extract a single generic type argument (for Vec<T>, Option<T>)
 fn extract_single_generic(args: &PathArguments) -> Option<Type> {
     if let PathArguments::AngleBracketed(angle) = args {
@@ -150,6 +188,22 @@ fn extract_single_generic(args: &PathArguments) -> Option<Type> {
     None
 }
 
+/// Extract all generic type arguments, skipping lifetimes and consts (for Page<T>, State<'a, T>)
+fn extract_type_generics(args: &PathArguments) -> Vec<Type> {
+    if let PathArguments::AngleBracketed(angle) = args {
+        angle
+            .args
+            .iter()
+            .filter_map(|arg| match arg {
+                GenericArgument::Type(ty) => Some(ty.clone()),
+                _ => None,
+            })
+            .collect()
+    } else {
+        Vec::new()
+    }
+}
+
 /// Extract two generic type arguments (for HashMap<K, V>)
 fn extract_two_generics(args: &PathArguments) -> Option<(Type, Type)> {
     if let PathArguments::AngleBracketed(angle) = args {
@@ -190,6 +244,80 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_transparent_wrappers() {
+        let ty = parse_type_str("Box<Node>");
+        assert_eq!(parse_type(&ty), RustType::Custom("Node".to_string()));
+
+        let ty = parse_type_str("std::sync::Arc<Settings>");
+        assert_eq!(parse_type(&ty), RustType::Custom("Settings".to_string()));
+
+        let ty = parse_type_str("Rc<RefCell<i32>>");
+        assert_eq!(parse_type(&ty), RustType::Primitive("i32".to_string()));
+
+        let ty = parse_type_str("Option<Box<Node>>");
+        assert_eq!(
+            parse_type(&ty),
+            RustType::Option(Box::new(RustType::Custom("Node".to_string())))
+        );
+    }
+
+    #[test]
+    fn test_parse_sequence_collections() {
+        for collection in ["HashSet", "BTreeSet", "VecDeque", "LinkedList", "indexmap::IndexSet"] {
+            let ty = parse_type_str(&format!("{}<String>", collection));
+            assert_eq!(
+                parse_type(&ty),
+                RustType::Vec(Box::new(RustType::Primitive("String".to_string()))),
+                "{} should parse as a sequence",
+                collection
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_index_map() {
+        let ty = parse_type_str("indexmap::IndexMap<String, i32>");
+        assert_eq!(
+            parse_type(&ty),
+            RustType::HashMap {
+                key: Box::new(RustType::Primitive("String".to_string())),
+                value: Box::new(RustType::Primitive("i32".to_string())),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_fixed_array() {
+        let ty = parse_type_str("[u8; 32]");
+        assert_eq!(
+            parse_type(&ty),
+            RustType::Array {
+                elem: Box::new(RustType::Primitive("u8".to_string())),
+                len: 32,
+            }
+        );
+
+        // Non-literal lengths fall back to Vec
+        let ty = parse_type_str("[f32; N]");
+        assert_eq!(
+            parse_type(&ty),
+            RustType::Vec(Box::new(RustType::Primitive("f32".to_string())))
+        );
+    }
+
+    #[test]
+    fn test_parse_cow() {
+        let ty = parse_type_str("Cow<'a, str>");
+        assert_eq!(parse_type(&ty), RustType::Primitive("String".to_string()));
+
+        let ty = parse_type_str("Cow<'static, [u8]>");
+        assert_eq!(
+            parse_type(&ty),
+            RustType::Vec(Box::new(RustType::Primitive("u8".to_string())))
+        );
+    }
+
     #[test]
     fn test_parse_primitive_integers() {
         for int_type in ["i8", "i16", "i32", "i64", "i128", "isize"] {
@@ -284,7 +412,7 @@ mod tests {
     fn test_parse_result_type() {
         let ty = parse_type_str("Result<User, String>");
         match parse_type(&ty) {
-            RustType::Result(ok) => match *ok {
+            RustType::Result { ok, .. } => match *ok {
                 RustType::Custom(name) => assert_eq!(name, "User"),
                 other => panic!("Expected Custom inside Result, got {:?}", other),
             },
@@ -388,7 +516,7 @@ mod tests {
     fn test_parse_result_with_vec() {
         let ty = parse_type_str("Result<Vec<Item>, String>");
         match parse_type(&ty) {
-            RustType::Result(ok) => match *ok {
+            RustType::Result { ok, .. } => match *ok {
                 RustType::Vec(inner) => match *inner {
                     RustType::Custom(name) => assert_eq!(name, "Item"),
                     other => panic!("Expected Custom, got {:?}", other),
@@ -422,6 +550,60 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_generic_custom_type() {
+        let ty = parse_type_str("Page<User>");
+        match parse_type(&ty) {
+            RustType::CustomGeneric { name, args } => {
+                assert_eq!(name, "Page");
+                assert_eq!(args, vec![RustType::Custom("User".to_string())]);
+            }
+            other => panic!("Expected CustomGeneric, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_generic_custom_type_nested() {
+        let ty = parse_type_str("ApiResponse<Vec<Item>>");
+        match parse_type(&ty) {
+            RustType::CustomGeneric { name, args } => {
+                assert_eq!(name, "ApiResponse");
+                assert_eq!(
+                    args,
+                    vec![RustType::Vec(Box::new(RustType::Custom("Item".to_string())))]
+                );
+            }
+            other => panic!("Expected CustomGeneric, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_generic_custom_type_skips_lifetimes() {
+        let ty = parse_type_str("State<'a, AppState>");
+        match parse_type(&ty) {
+            RustType::CustomGeneric { name, args } => {
+                assert_eq!(name, "State");
+                assert_eq!(args, vec![RustType::Custom("AppState".to_string())]);
+            }
+            other => panic!("Expected CustomGeneric, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_generic_custom_type_with_param_in_context() {
+        let ty = parse_type_str("Wrapper<T>");
+        let mut generics = HashSet::new();
+        generics.insert("T".to_string());
+
+        match parse_type_with_context(&ty, &generics) {
+            RustType::CustomGeneric { name, args } => {
+                assert_eq!(name, "Wrapper");
+                assert_eq!(args, vec![RustType::Generic("T".to_string())]);
+            }
+            other => panic!("Expected CustomGeneric, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_parse_external_types() {
         for ext_type in [
@@ -433,6 +615,8 @@ mod tests {
             "Url",
             "IpAddr",
             "Duration",
+            "NonZeroU32",
+            "std::time::SystemTime",
         ] {
             let ty = parse_type_str(ext_type);
             match parse_type(&ty) {
@@ -440,6 +624,22 @@ mod tests {
                 other => panic!("Expected Primitive({}), got {:?}", ext_type, other),
             }
         }
+
+        // Type arguments are kept until the pipeline knows which crate the type comes from
+        let ty = parse_type_str("chrono::DateTime<chrono::Utc>");
+        assert_eq!(
+            parse_type(&ty),
+            RustType::CustomGeneric {
+                name: "chrono::DateTime".to_string(),
+                args: vec![RustType::Custom("chrono::Utc".to_string())],
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_integer_wrappers() {
+        let ty = parse_type_str("std::num::Wrapping<u32>");
+        assert_eq!(parse_type(&ty), RustType::Primitive("u32".to_string()));
     }
 
     #[test]
@@ -459,5 +659,27 @@ mod tests {
             other => panic!("Expected Primitive(String) from &String, got {:?}", other),
         }
     }
-}
 
+    #[test]
+    fn test_parse_result_error_type() {
+        let ty = parse_type_str("Result<User, AppError>");
+        assert_eq!(
+            parse_type(&ty),
+            RustType::Result {
+                ok: Box::new(RustType::Custom("User".to_string())),
+                err: Some(Box::new(RustType::Custom("AppError".to_string()))),
+            }
+        );
+
+        // `Result<T>` aliases don't name the error type
+        let ty = parse_type_str("anyhow::Result<User>");
+        assert_eq!(
+            parse_type(&ty),
+            RustType::Result {
+                ok: Box::new(RustType::Custom("User".to_string())),
+                err: None,
+            }
+        );
+    }
+
+}
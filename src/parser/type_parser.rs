@@ -1,10 +1,13 @@
-use crate::models::{EnumVariant, RustEnum, RustStruct, StructField, VariantData, EnumRepresentation};
-use crate::utils::{to_camel_case, to_kebab_case, to_screaming_kebab_case, to_screaming_snake_case, to_snake_case};
+use crate::models::{EnumVariant, FieldPresence, RustEnum, RustStruct, RustTypeAlias, StructField, StructKind, VariantData, EnumRepresentation};
+use crate::utils::{apply_field_rename_all, to_camel_case, to_kebab_case, to_screaming_kebab_case, to_screaming_snake_case, to_snake_case};
 use anyhow::Result;
 use std::collections::HashSet;
 use std::path::Path;
+use syn::ext::IdentExt;
 use syn::{Fields, Item, ItemEnum, ItemStruct, Expr, Lit, Meta};
 
+use super::docs::parse_docs;
+use super::ts_attrs::{for_each_ts_option, parse_ts_naming, ts_option_str};
 use super::type_extractor::parse_type_with_context;
 
 /// Serde container attributes that affect naming
@@ -18,6 +21,12 @@ struct SerdeContainerAttrs {
     content: Option<String>,
     /// Whether the enum is untagged
     untagged: bool,
+    /// Value of rename_all_fields attribute (enums only, applies to struct variant fields)
+    rename_all_fields: Option<String>,
+    /// Whether the struct is transparent
+    transparent: bool,
+    /// Whether missing fields are filled from Default (container-level `default`)
+    default: bool,
 }
 
 /// Parse a Rust source file and extract structs and enums
@@ -31,6 +40,52 @@ pub fn parse_types_expanded(content: &str, source_file: &Path) -> Result<(Vec<Ru
     parse_types_internal(content, source_file, true)
 }
 
+/// Parse a Rust source file and extract type aliases (`type Foo<T> = Bar<T>;`)
+///
+/// All aliases are returned; the pipeline only emits the ones reachable from commands.
+pub fn parse_type_aliases(content: &str, source_file: &Path) -> Result<Vec<RustTypeAlias>> {
+    let syntax = syn::parse_file(content)?;
+    let mut aliases = Vec::new();
+    parse_type_alias_items(&syntax.items, source_file, &mut aliases);
+    Ok(aliases)
+}
+
+fn parse_type_alias_items(items: &[Item], source_file: &Path, aliases: &mut Vec<RustTypeAlias>) {
+    for item in items {
+        match item {
+            Item::Type(item_type) => {
+                let generics: Vec<String> = item_type
+                    .generics
+                    .params
+                    .iter()
+                    .filter_map(|param| {
+                        if let syn::GenericParam::Type(type_param) = param {
+                            Some(type_param.ident.to_string())
+                        } else {
+                            None
+                        }
+                    })
+                    .collect();
+                let generic_params: HashSet<String> = generics.iter().cloned().collect();
+
+                aliases.push(RustTypeAlias {
+                    name: item_type.ident.to_string(),
+                    generics,
+                    target: parse_type_with_context(&item_type.ty, &generic_params),
+                    source_file: source_file.to_path_buf(),
+                    docs: parse_docs(&item_type.attrs),
+                });
+            }
+            Item::Mod(module) => {
+                if let Some((_, mod_items)) = &module.content {
+                    parse_type_alias_items(mod_items, source_file, aliases);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
 /// Internal parsing function
 fn parse_types_internal(content: &str, source_file: &Path, expanded: bool) -> Result<(Vec<RustStruct>, Vec<RustEnum>)> {
     let syntax = syn::parse_file(content)?;
@@ -123,8 +178,16 @@ fn parse_items(
                     is_serializable(&item_struct.attrs)
                 };
                 
-                if should_include {
-                    if let Some(s) = parse_struct(item_struct, source_file) {
+                // #[ts(skip)] types are left out of the bindings
+                if should_include && !parse_ts_naming(&item_struct.attrs).skip {
+                    if let Some(s) = parse_overridden_type(
+                        &item_struct.ident,
+                        &item_struct.generics,
+                        &item_struct.attrs,
+                        source_file,
+                    )
+                    .or_else(|| parse_struct(item_struct, source_file))
+                    {
                         structs.push(s);
                     }
                 }
@@ -140,8 +203,13 @@ fn parse_items(
                     is_serializable(&item_enum.attrs)
                 };
                 
-                if should_include {
-                    if let Some(e) = parse_enum(item_enum, source_file) {
+                // #[ts(skip)] types are left out of the bindings
+                if should_include && !parse_ts_naming(&item_enum.attrs).skip {
+                    if let Some(s) =
+                        parse_overridden_type(&item_enum.ident, &item_enum.generics, &item_enum.attrs, source_file)
+                    {
+                        structs.push(s);
+                    } else if let Some(e) = parse_enum(item_enum, source_file) {
                         enums.push(e);
                     }
                 }
@@ -261,26 +329,41 @@ fn parse_struct(item: &ItemStruct, source_file: &Path) -> Option<RustStruct> {
     // Create a set for efficient lookup when parsing field types
     let generic_params: HashSet<String> = generics.iter().cloned().collect();
 
+    // Parse container-level serde attributes (rename_all, transparent, default)
+    let container_attrs = parse_serde_container_attrs(&item.attrs);
+
+    let kind = match &item.fields {
+        Fields::Named(_) => StructKind::Named,
+        Fields::Unnamed(_) => StructKind::Tuple,
+        Fields::Unit => StructKind::Unit,
+    };
+
     let fields = match &item.fields {
         Fields::Named(named) => named
             .named
             .iter()
             .filter_map(|field| {
-                let field_name = field.ident.as_ref()?.to_string();
-                let field_type = parse_type_with_context(&field.ty, &generic_params);
+                let field_type = parse_field_type(field, &generic_params);
 
-                // Check for serde rename attribute
-                let explicit_rename = get_serde_rename(&field.attrs);
-                let final_name = explicit_rename.clone().unwrap_or(field_name);
+                // Resolve the serialized name (serde rename, rename_all, or camelCase)
+                let (final_name, has_explicit_rename) =
+                    resolve_field_name(field.ident.as_ref()?, &field.attrs, &container_attrs.rename_all);
 
                 // Check for #[ts(optional)] attribute
                 let use_optional = has_ts_optional(&field.attrs, &field_type);
 
+                // Check for serde skip / skip_serializing_if / default
+                let (presence, omits_none) =
+                    get_field_presence(&field.attrs, &field_type, container_attrs.default);
+
                 Some(StructField {
                     name: final_name,
                     ty: field_type,
-                    has_explicit_rename: explicit_rename.is_some(),
-                    use_optional,
+                    has_explicit_rename,
+                    use_optional: use_optional || omits_none,
+                    presence,
+                    flatten: has_serde_flatten(&field.attrs),
+                    docs: parse_docs(&field.attrs),
                 })
             })
             .collect(),
@@ -290,11 +373,19 @@ fn parse_struct(item: &ItemStruct, source_file: &Path) -> Option<RustStruct> {
                 .unnamed
                 .iter()
                 .enumerate()
-                .map(|(i, field)| StructField {
-                    name: format!("field{}", i),
-                    ty: parse_type_with_context(&field.ty, &generic_params),
-                    has_explicit_rename: false,
-                    use_optional: false,
+                .map(|(i, field)| {
+                    let field_type = parse_field_type(field, &generic_params);
+                    let (presence, _) =
+                        get_field_presence(&field.attrs, &field_type, container_attrs.default);
+                    StructField {
+                        name: format!("field{}", i),
+                        ty: field_type,
+                        has_explicit_rename: false,
+                        use_optional: false,
+                        presence,
+                        flatten: false,
+                        docs: parse_docs(&field.attrs),
+                    }
                 })
                 .collect()
         }
@@ -306,6 +397,12 @@ fn parse_struct(item: &ItemStruct, source_file: &Path) -> Option<RustStruct> {
         generics,
         fields,
         source_file: source_file.to_path_buf(),
+        transparent: container_attrs.transparent,
+        kind,
+        docs: parse_docs(&item.attrs),
+        event: parse_ts_event(&item.attrs),
+        ts_name: parse_ts_naming(&item.attrs).rename,
+        export: has_ts_export(&item.attrs),
     })
 }
 
@@ -351,24 +448,41 @@ fn parse_enum(item: &ItemEnum, source_file: &Path) -> Option<RustEnum> {
     let variants = item
         .variants
         .iter()
+        .filter(|variant| !parse_ts_naming(&variant.attrs).skip)
         .map(|variant| {
             let variant_name = variant.ident.to_string();
 
-            // Check for serde rename attribute on variant
-            let explicit_rename = get_serde_rename(&variant.attrs);
+            // Check for ts or serde rename attribute on variant
+            let explicit_rename = parse_ts_naming(&variant.attrs)
+                .rename
+                .or_else(|| get_serde_rename(&variant.attrs));
             let final_name = explicit_rename.clone()
                 .or_else(|| apply_rename_all(&variant_name, &container_attrs.rename_all))
                 .unwrap_or(variant_name.clone());
             let has_explicit_rename = explicit_rename.is_some() || container_attrs.rename_all.is_some();
 
+            // Struct variant fields follow the variant's own rename_all, then the enum's rename_all_fields
+            let fields_rename_all = parse_serde_container_attrs(&variant.attrs)
+                .rename_all
+                .or_else(|| container_attrs.rename_all_fields.clone());
+
             let data = match &variant.fields {
                 Fields::Unit => VariantData::Unit,
                 Fields::Unnamed(unnamed) => {
-                    let types = unnamed
+                    let mut types: Vec<_> = unnamed
                         .unnamed
                         .iter()
-                        .map(|f| parse_type_with_context(&f.ty, &generic_params))
+                        .filter_map(|f| {
+                            let ty = parse_field_type(f, &generic_params);
+                            let (presence, _) = get_field_presence(&f.attrs, &ty, false);
+                            (presence != FieldPresence::Skipped).then_some(ty)
+                        })
                         .collect();
+                    // serde picks newtype vs tuple from the declared field count, so a
+                    // single field left after skipping still serializes as a 1-tuple
+                    if unnamed.unnamed.len() != 1 && types.len() == 1 {
+                        types = vec![crate::models::RustType::Tuple(types)];
+                    }
                     VariantData::Tuple(types)
                 }
                 Fields::Named(named) => {
@@ -376,16 +490,20 @@ fn parse_enum(item: &ItemEnum, source_file: &Path) -> Option<RustEnum> {
                         .named
                         .iter()
                         .filter_map(|field| {
-                            let field_name = field.ident.as_ref()?.to_string();
-                            let field_type = parse_type_with_context(&field.ty, &generic_params);
-                            let explicit_rename = get_serde_rename(&field.attrs);
-                            let final_name = explicit_rename.clone().unwrap_or(field_name);
+                            let field_type = parse_field_type(field, &generic_params);
+                            let (final_name, has_explicit_rename) =
+                                resolve_field_name(field.ident.as_ref()?, &field.attrs, &fields_rename_all);
                             let use_optional = has_ts_optional(&field.attrs, &field_type);
+                            let (presence, omits_none) =
+                                get_field_presence(&field.attrs, &field_type, false);
                             Some(StructField {
                                 name: final_name,
                                 ty: field_type,
-                                has_explicit_rename: explicit_rename.is_some(),
-                                use_optional,
+                                has_explicit_rename,
+                                use_optional: use_optional || omits_none,
+                                presence,
+                                flatten: has_serde_flatten(&field.attrs),
+                                docs: parse_docs(&field.attrs),
                             })
                         })
                         .collect();
@@ -397,6 +515,7 @@ fn parse_enum(item: &ItemEnum, source_file: &Path) -> Option<RustEnum> {
                 name: final_name,
                 data,
                 has_explicit_rename,
+                docs: parse_docs(&variant.attrs),
             }
         })
         .collect();
@@ -407,6 +526,10 @@ fn parse_enum(item: &ItemEnum, source_file: &Path) -> Option<RustEnum> {
         variants,
         source_file: source_file.to_path_buf(),
         representation,
+        docs: parse_docs(&item.attrs),
+        event: parse_ts_event(&item.attrs),
+        ts_name: parse_ts_naming(&item.attrs).rename,
+        export: has_ts_export(&item.attrs),
     })
 }
 
@@ -437,35 +560,260 @@ fn get_serde_rename(attrs: &[syn::Attribute]) -> Option<String> {
     None
 }
 
-/// Check if a field has #[ts(optional)] attribute and validate it's on Option<T>
-fn has_ts_optional(attrs: &[syn::Attribute], ty: &crate::models::RustType) -> bool {
+/// Resolve the serialized name of a named field
+///
+/// Priority: #[ts(rename)] > #[serde(rename)] > container rename_all > default camelCase conversion.
+/// Returns the name and whether it was given explicitly.
+fn resolve_field_name(
+    ident: &syn::Ident,
+    attrs: &[syn::Attribute],
+    rename_all: &Option<String>,
+) -> (String, bool) {
+    if let Some(renamed) = parse_ts_naming(attrs).rename.or_else(|| get_serde_rename(attrs)) {
+        return (renamed, true);
+    }
+
+    // serde strips the raw identifier prefix (r#type -> type)
+    let field_name = ident.unraw().to_string();
+    match apply_field_rename_all(&field_name, rename_all) {
+        Some(renamed) => (renamed, true),
+        None => (to_camel_case(&field_name), false),
+    }
+}
+
+/// Chrono's `#[serde(with = "...")]` helpers that serialize a `DateTime` as an integer timestamp
+const CHRONO_TIMESTAMP_MODULES: &[&str] = &["ts_seconds", "ts_milliseconds", "ts_microseconds", "ts_nanoseconds"];
+
+/// Parse a field's type, taking `#[ts(type/as)]` overrides and serde `with` helpers that change its shape into account
+fn parse_field_type(field: &syn::Field, generic_params: &HashSet<String>) -> crate::models::RustType {
+    if let Some(ty) = parse_ts_override(&field.attrs, generic_params) {
+        return ty;
+    }
+    let ty = parse_type_with_context(&field.ty, generic_params);
+
+    for attr in &field.attrs {
+        if let Meta::List(meta_list) = &attr.meta {
+            if meta_list.path.is_ident("serde") {
+                if let Ok(nested) = meta_list.parse_args_with(
+                    syn::punctuated::Punctuated::<Meta, syn::Token![,]>::parse_terminated,
+                ) {
+                    for meta in nested {
+                        if let Meta::NameValue(nv) = meta {
+                            if nv.path.is_ident("with") || nv.path.is_ident("serialize_with") {
+                                if let Expr::Lit(expr_lit) = &nv.value {
+                                    if let Lit::Str(lit_str) = &expr_lit.lit {
+                                        if let Some(timestamp) = chrono_timestamp_type(&lit_str.value()) {
+                                            return timestamp;
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    ty
+}
+
+/// Type of a chrono timestamp helper (`chrono::serde::ts_seconds` -> `i64`, `ts_seconds_option` -> `Option<i64>`)
+fn chrono_timestamp_type(path: &str) -> Option<crate::models::RustType> {
+    let module = path.rsplit("::").find(|s| *s != "serialize")?;
+    let timestamp = crate::models::RustType::Primitive("i64".to_string());
+    if CHRONO_TIMESTAMP_MODULES.contains(&module) {
+        return Some(timestamp);
+    }
+    let module = module.strip_suffix("_option")?;
+    CHRONO_TIMESTAMP_MODULES
+        .contains(&module)
+        .then(|| crate::models::RustType::Option(Box::new(timestamp)))
+}
+
+/// Determine whether a field is always serialized, may be missing, or is skipped entirely
+///
+/// Returns the presence and whether the field is an `Option` skipped when `None`
+/// (`skip_serializing_if = "Option::is_none"`), which should be emitted as `field?: T`.
+fn get_field_presence(
+    attrs: &[syn::Attribute],
+    ty: &crate::models::RustType,
+    container_default: bool,
+) -> (FieldPresence, bool) {
+    let mut skipped = parse_ts_naming(attrs).skip;
+    let mut maybe_missing = false;
+    let mut has_default = container_default;
+    let mut omits_none = false;
+
     for attr in attrs {
         if let Meta::List(meta_list) = &attr.meta {
-            if meta_list.path.is_ident("ts") {
+            if meta_list.path.is_ident("serde") {
                 if let Ok(nested) = meta_list.parse_args_with(
                     syn::punctuated::Punctuated::<Meta, syn::Token![,]>::parse_terminated,
                 ) {
                     for meta in nested {
-                        if let Meta::Path(path) = meta {
-                            if path.is_ident("optional") {
-                                // Validate that the type is Option<T>
-                                if matches!(ty, crate::models::RustType::Option(_)) {
-                                    return true;
-                                } else {
-                                    eprintln!(
-                                        "Warning: #[ts(optional)] is only valid on Option<T> fields, ignoring"
-                                    );
+                        match meta {
+                            Meta::Path(path) if path.is_ident("skip") => skipped = true,
+                            Meta::Path(path) if path.is_ident("skip_serializing") => maybe_missing = true,
+                            Meta::Path(path) if path.is_ident("default") => has_default = true,
+                            Meta::NameValue(nv) if nv.path.is_ident("skip_serializing_if") => {
+                                maybe_missing = true;
+                                if let Expr::Lit(expr_lit) = &nv.value {
+                                    if let Lit::Str(lit_str) = &expr_lit.lit {
+                                        let predicate = lit_str.value().replace(' ', "");
+                                        omits_none = predicate == "Option::is_none"
+                                            && matches!(ty, crate::models::RustType::Option(_));
+                                    }
                                 }
                             }
+                            Meta::NameValue(nv) if nv.path.is_ident("default") => has_default = true,
+                            _ => {}
                         }
                     }
                 }
             }
         }
     }
+
+    let presence = if skipped {
+        FieldPresence::Skipped
+    } else if maybe_missing {
+        FieldPresence::Optional
+    } else if has_default {
+        FieldPresence::DefaultOnInput
+    } else {
+        FieldPresence::Always
+    };
+    (presence, omits_none && !skipped)
+}
+
+/// Check if a field has #[serde(flatten)]
+fn has_serde_flatten(attrs: &[syn::Attribute]) -> bool {
+    for attr in attrs {
+        if let Meta::List(meta_list) = &attr.meta {
+            if meta_list.path.is_ident("serde") {
+                if let Ok(nested) = meta_list.parse_args_with(
+                    syn::punctuated::Punctuated::<Meta, syn::Token![,]>::parse_terminated,
+                ) {
+                    if nested.iter().any(|meta| matches!(meta, Meta::Path(path) if path.is_ident("flatten"))) {
+                        return true;
+                    }
+                }
+            }
+        }
+    }
     false
 }
 
+/// Check if a field has #[ts(optional)] attribute and validate it's on Option<T>
+fn has_ts_optional(attrs: &[syn::Attribute], ty: &crate::models::RustType) -> bool {
+    let mut optional = false;
+    for_each_ts_option(attrs, |meta| {
+        if meta.path.is_ident("optional") {
+            optional = true;
+        }
+        Ok(())
+    });
+    if optional && !matches!(ty, crate::models::RustType::Option(_)) {
+        eprintln!("Warning: #[ts(optional)] is only valid on Option<T> fields, ignoring");
+        return false;
+    }
+    optional
+}
+
+/// Get the type given by a #[ts(type = "...")] (verbatim TypeScript) or #[ts(as = "...")] (Rust type) attribute
+fn parse_ts_override(
+    attrs: &[syn::Attribute],
+    generic_params: &HashSet<String>,
+) -> Option<crate::models::RustType> {
+    let mut result = None;
+    for_each_ts_option(attrs, |meta| {
+        if result.is_some() {
+            return Ok(());
+        }
+        if meta.path.is_ident("type") {
+            result = Some(crate::models::RustType::Verbatim(ts_option_str(meta)?.value()));
+        } else if meta.path.is_ident("as") {
+            let lit = ts_option_str(meta)?;
+            match lit.parse::<syn::Type>() {
+                Ok(ty) => result = Some(parse_type_with_context(&ty, generic_params)),
+                Err(_) => eprintln!(
+                    "Warning: #[ts(as = \"{}\")] is not a valid Rust type, ignoring",
+                    lit.value()
+                ),
+            }
+        }
+        Ok(())
+    });
+    result
+}
+
+/// A struct or enum whose whole type is overridden with #[ts(type/as)], emitted as a type alias
+fn parse_overridden_type(
+    ident: &syn::Ident,
+    generics: &syn::Generics,
+    attrs: &[syn::Attribute],
+    source_file: &Path,
+) -> Option<RustStruct> {
+    let generics: Vec<String> = generics
+        .params
+        .iter()
+        .filter_map(|param| {
+            if let syn::GenericParam::Type(type_param) = param {
+                Some(type_param.ident.to_string())
+            } else {
+                None
+            }
+        })
+        .collect();
+    let generic_params: HashSet<String> = generics.iter().cloned().collect();
+    let ty = parse_ts_override(attrs, &generic_params)?;
+
+    // A transparent newtype serializes exactly like the override type
+    Some(RustStruct {
+        name: ident.to_string(),
+        generics,
+        fields: vec![StructField {
+            name: "field0".to_string(),
+            ty,
+            has_explicit_rename: false,
+            use_optional: false,
+            presence: FieldPresence::Always,
+            flatten: false,
+            docs: Default::default(),
+        }],
+        source_file: source_file.to_path_buf(),
+        transparent: true,
+        kind: StructKind::Tuple,
+        docs: parse_docs(attrs),
+        event: parse_ts_event(attrs),
+        ts_name: parse_ts_naming(attrs).rename,
+        export: has_ts_export(attrs),
+    })
+}
+
+/// Check if a type has #[ts(export)], which generates it even if no command uses it
+fn has_ts_export(attrs: &[syn::Attribute]) -> bool {
+    let mut export = false;
+    for_each_ts_option(attrs, |meta| {
+        export |= meta.path.is_ident("export");
+        Ok(())
+    });
+    export
+}
+
+/// Get the event name from a #[ts(event = "...")] attribute
+fn parse_ts_event(attrs: &[syn::Attribute]) -> Option<String> {
+    let mut event = None;
+    for_each_ts_option(attrs, |meta| {
+        if meta.path.is_ident("event") && event.is_none() {
+            event = Some(ts_option_str(meta)?.value());
+        }
+        Ok(())
+    });
+    event
+}
+
 /// Parse serde container attributes (rename_all, etc.)
 fn parse_serde_container_attrs(attrs: &[syn::Attribute]) -> SerdeContainerAttrs {
     let mut result = SerdeContainerAttrs::default();
@@ -497,12 +845,25 @@ fn parse_serde_container_attrs(attrs: &[syn::Attribute]) -> SerdeContainerAttrs
                                             result.content = Some(lit_str.value());
                                         }
                                     }
+                                } else if nv.path.is_ident("rename_all_fields") {
+                                    if let Expr::Lit(expr_lit) = &nv.value {
+                                        if let Lit::Str(lit_str) = &expr_lit.lit {
+                                            result.rename_all_fields = Some(lit_str.value());
+                                        }
+                                    }
+                                } else if nv.path.is_ident("default") {
+                                    // #[serde(default = "path")] behaves like default for missing fields
+                                    result.default = true;
                                 }
                             }
-                            Meta::Path(path) => {
-                                if path.is_ident("untagged") {
-                                    result.untagged = true;
-                                }
+                            Meta::Path(path) if path.is_ident("untagged") => {
+                                result.untagged = true;
+                            }
+                            Meta::Path(path) if path.is_ident("transparent") => {
+                                result.transparent = true;
+                            }
+                            Meta::Path(path) if path.is_ident("default") => {
+                                result.default = true;
                             }
                             _ => {}
                         }
@@ -512,6 +873,11 @@ fn parse_serde_container_attrs(attrs: &[syn::Attribute]) -> SerdeContainerAttrs
         }
     }
 
+    // #[ts(rename_all)] takes precedence over serde's
+    if let Some(rename_all) = parse_ts_naming(attrs).rename_all {
+        result.rename_all = Some(rename_all);
+    }
+
     result
 }
 
@@ -531,8 +897,6 @@ fn apply_rename_all(name: &str, rename_all: &Option<String>) -> Option<String> {
     })
 }
 
-
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -692,6 +1056,60 @@ mod tests {
         assert_eq!(point.fields.len(), 2);
         assert_eq!(point.fields[0].name, "field0");
         assert_eq!(point.fields[1].name, "field1");
+        assert_eq!(point.kind, StructKind::Tuple);
+    }
+
+    #[test]
+    fn test_parse_type_aliases() {
+        let code = r#"
+            pub type UserList = Vec<User>;
+            pub type Page<T> = Paginated<T>;
+
+            mod inner {
+                pub type Ids = Vec<u64>;
+            }
+        "#;
+
+        let aliases = parse_type_aliases(code, &test_path()).unwrap();
+        assert_eq!(aliases.len(), 3);
+
+        assert_eq!(aliases[0].name, "UserList");
+        assert_eq!(
+            aliases[0].target,
+            RustType::Vec(Box::new(RustType::Custom("User".to_string())))
+        );
+
+        assert_eq!(aliases[1].generics, vec!["T"]);
+        assert_eq!(
+            aliases[1].target,
+            RustType::CustomGeneric {
+                name: "Paginated".to_string(),
+                args: vec![RustType::Generic("T".to_string())],
+            }
+        );
+
+        assert_eq!(aliases[2].name, "Ids");
+    }
+
+    #[test]
+    fn test_parse_struct_kinds() {
+        let code = r#"
+            #[derive(Serialize)]
+            pub struct User { id: u64 }
+
+            #[derive(Serialize)]
+            pub struct UserId(u64);
+
+            #[derive(Serialize)]
+            pub struct Marker;
+        "#;
+
+        let (structs, _) = parse_types(code, &test_path()).unwrap();
+        assert_eq!(structs[0].kind, StructKind::Named);
+        assert_eq!(structs[1].kind, StructKind::Tuple);
+        assert_eq!(structs[1].fields.len(), 1);
+        assert_eq!(structs[2].kind, StructKind::Unit);
+        assert!(structs[2].fields.is_empty());
     }
 
     #[test]
@@ -757,6 +1175,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_enum_tuple_variant_skipped_fields() {
+        let code = r#"
+            #[derive(Serialize)]
+            pub enum Message {
+                Tagged(#[serde(skip)] u8, String),
+                Hidden(u8, #[ts(skip)] String, bool),
+            }
+        "#;
+
+        let (_, enums) = parse_types(code, &test_path()).unwrap();
+        assert_eq!(
+            enums[0].variants[0].data,
+            VariantData::Tuple(vec![RustType::Tuple(vec![RustType::Primitive("String".to_string())])])
+        );
+        assert_eq!(
+            enums[0].variants[1].data,
+            VariantData::Tuple(vec![
+                RustType::Primitive("u8".to_string()),
+                RustType::Primitive("bool".to_string()),
+            ])
+        );
+    }
+
     #[test]
     fn test_parse_enum_with_struct_variant() {
         let code = r#"
@@ -808,6 +1250,186 @@ mod tests {
         assert!(!user.fields[1].has_explicit_rename, "Field without serde rename should have has_explicit_rename = false");
     }
 
+    #[test]
+    fn test_default_field_names_are_camel_case() {
+        let code = r#"
+            #[derive(Serialize)]
+            pub struct User {
+                pub user_id: i32,
+                pub r#type: String,
+            }
+        "#;
+
+        let (structs, _) = parse_types(code, &test_path()).unwrap();
+        let user = &structs[0];
+
+        assert_eq!(user.fields[0].name, "userId");
+        assert!(!user.fields[0].has_explicit_rename);
+        assert_eq!(user.fields[1].name, "type");
+    }
+
+    #[test]
+    fn test_struct_rename_all() {
+        let code = r#"
+            #[derive(Serialize)]
+            #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+            pub struct Config {
+                pub api_key: String,
+                #[serde(rename = "timeout")]
+                pub timeout_ms: u32,
+            }
+
+            #[derive(Serialize)]
+            #[serde(rename_all = "PascalCase")]
+            pub struct Legacy {
+                pub user_name: String,
+            }
+
+            #[derive(Serialize)]
+            #[serde(rename_all = "kebab-case")]
+            pub struct Header {
+                pub content_type: String,
+            }
+        "#;
+
+        let (structs, _) = parse_types(code, &test_path()).unwrap();
+
+        assert_eq!(structs[0].fields[0].name, "API_KEY");
+        assert!(structs[0].fields[0].has_explicit_rename);
+        // Explicit rename wins over rename_all
+        assert_eq!(structs[0].fields[1].name, "timeout");
+        assert_eq!(structs[1].fields[0].name, "UserName");
+        assert_eq!(structs[2].fields[0].name, "content-type");
+    }
+
+    #[test]
+    fn test_struct_transparent_and_default() {
+        let code = r#"
+            #[derive(Serialize, Deserialize)]
+            #[serde(transparent)]
+            pub struct UserId(u64);
+
+            #[derive(Serialize, Deserialize)]
+            #[serde(default)]
+            pub struct Settings {
+                pub volume: f32,
+            }
+
+            #[derive(Serialize, Deserialize)]
+            pub struct Plain {
+                pub volume: f32,
+            }
+        "#;
+
+        let (structs, _) = parse_types(code, &test_path()).unwrap();
+
+        assert!(structs[0].transparent);
+        assert!(!structs[1].transparent);
+        assert_eq!(structs[1].fields[0].presence, FieldPresence::DefaultOnInput);
+        assert_eq!(structs[2].fields[0].presence, FieldPresence::Always);
+    }
+
+    #[test]
+    fn test_field_presence_attributes() {
+        let code = r#"
+            #[derive(Serialize, Deserialize)]
+            pub struct Account {
+                pub id: i32,
+                #[serde(skip)]
+                pub cache: Vec<u8>,
+                #[serde(skip_serializing)]
+                pub password: String,
+                #[serde(skip_serializing_if = "Option::is_none")]
+                pub nickname: Option<String>,
+                #[serde(skip_serializing_if = "Vec::is_empty")]
+                pub tags: Vec<String>,
+                #[serde(default)]
+                pub retries: u32,
+                #[serde(default = "default_limit")]
+                pub limit: u32,
+            }
+        "#;
+
+        let (structs, _) = parse_types(code, &test_path()).unwrap();
+        let fields = &structs[0].fields;
+
+        assert_eq!(fields[0].presence, FieldPresence::Always);
+        assert_eq!(fields[1].presence, FieldPresence::Skipped);
+        assert_eq!(fields[2].presence, FieldPresence::Optional);
+        assert_eq!(fields[3].presence, FieldPresence::Optional);
+        assert!(fields[3].use_optional, "Option::is_none should omit null");
+        assert_eq!(fields[4].presence, FieldPresence::Optional);
+        assert!(!fields[4].use_optional);
+        assert_eq!(fields[5].presence, FieldPresence::DefaultOnInput);
+        assert_eq!(fields[6].presence, FieldPresence::DefaultOnInput);
+    }
+
+    #[test]
+    fn test_field_presence_on_struct_variant() {
+        let code = r#"
+            #[derive(Serialize)]
+            pub enum Event {
+                Login {
+                    user_id: i32,
+                    #[serde(skip)]
+                    session: Session,
+                },
+            }
+        "#;
+
+        let (_, enums) = parse_types(code, &test_path()).unwrap();
+        match &enums[0].variants[0].data {
+            VariantData::Struct(fields) => {
+                assert_eq!(fields[0].presence, FieldPresence::Always);
+                assert_eq!(fields[1].presence, FieldPresence::Skipped);
+            }
+            other => panic!("Expected Struct variant, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_serde_flatten_field() {
+        let code = r#"
+            #[derive(Serialize)]
+            pub struct Item {
+                pub id: i32,
+                #[serde(flatten)]
+                pub meta: Metadata,
+            }
+        "#;
+
+        let (structs, _) = parse_types(code, &test_path()).unwrap();
+        let fields = &structs[0].fields;
+
+        assert!(!fields[0].flatten);
+        assert!(fields[1].flatten);
+    }
+
+    #[test]
+    fn test_struct_variant_field_rename_all() {
+        let code = r#"
+            #[derive(Serialize)]
+            #[serde(rename_all_fields = "snake_case")]
+            pub enum Event {
+                Login { user_id: i32 },
+                #[serde(rename_all = "UPPERCASE")]
+                Logout { user_id: i32 },
+            }
+        "#;
+
+        let (_, enums) = parse_types(code, &test_path()).unwrap();
+        let event = &enums[0];
+
+        match &event.variants[0].data {
+            VariantData::Struct(fields) => assert_eq!(fields[0].name, "user_id"),
+            other => panic!("Expected Struct variant, got {:?}", other),
+        }
+        match &event.variants[1].data {
+            VariantData::Struct(fields) => assert_eq!(fields[0].name, "USER_ID"),
+            other => panic!("Expected Struct variant, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_serde_rename_variant() {
         let code = r#"
@@ -985,4 +1607,252 @@ mod tests {
         let (structs, _) = super::parse_types(code, &test_path()).unwrap();
         assert_eq!(structs.len(), 0, "Regular parse should not find struct without derive");
     }
+
+    #[test]
+    fn test_parse_docs_on_types_fields_and_variants() {
+        let code = r#"
+            /// A registered user
+            #[derive(Serialize)]
+            pub struct User {
+                /// Unique ID
+                pub id: i32,
+                #[deprecated]
+                pub legacy: bool,
+            }
+
+            /// Current status
+            #[derive(Serialize)]
+            pub enum Status {
+                /// Everything is fine
+                Active,
+                Inactive,
+            }
+        "#;
+
+        let (structs, enums) = parse_types(code, &test_path()).unwrap();
+        assert_eq!(structs[0].docs.lines, vec!["A registered user"]);
+        assert_eq!(structs[0].fields[0].docs.lines, vec!["Unique ID"]);
+        assert_eq!(structs[0].fields[1].docs.deprecated, Some(String::new()));
+        assert_eq!(enums[0].docs.lines, vec!["Current status"]);
+        assert_eq!(enums[0].variants[0].docs.lines, vec!["Everything is fine"]);
+        assert!(enums[0].variants[1].docs.is_empty());
+    }
+
+    #[test]
+    fn test_parse_ts_event_attribute() {
+        let code = r#"
+            #[derive(Serialize, TS)]
+            #[ts(event = "download-progress")]
+            pub struct DownloadProgress {
+                pub percent: f32,
+            }
+
+            #[derive(Serialize, TS)]
+            #[ts(event = "session-changed")]
+            pub enum SessionChanged {
+                LoggedIn,
+                LoggedOut,
+            }
+
+            #[derive(Serialize)]
+            pub struct Plain {
+                pub id: u32,
+            }
+        "#;
+
+        let (structs, enums) = parse_types(code, &test_path()).unwrap();
+        assert_eq!(structs[0].event.as_deref(), Some("download-progress"));
+        assert_eq!(structs[1].event, None);
+        assert_eq!(enums[0].event.as_deref(), Some("session-changed"));
+    }
+
+    #[test]
+    fn test_chrono_timestamp_fields() {
+        let code = r#"
+            #[derive(Serialize)]
+            pub struct Event {
+                #[serde(with = "chrono::serde::ts_seconds")]
+                pub created_at: DateTime<Utc>,
+                #[serde(with = "ts_milliseconds_option")]
+                pub deleted_at: Option<DateTime<Utc>>,
+                pub updated_at: DateTime<Utc>,
+            }
+        "#;
+
+        let (structs, _) = parse_types(code, &test_path()).unwrap();
+        let fields = &structs[0].fields;
+        assert_eq!(fields[0].ty, RustType::Primitive("i64".to_string()));
+        assert_eq!(
+            fields[1].ty,
+            RustType::Option(Box::new(RustType::Primitive("i64".to_string())))
+        );
+        assert!(matches!(&fields[2].ty, RustType::CustomGeneric { name, .. } if name == "DateTime"));
+    }
+
+    #[test]
+    fn test_ts_type_and_as_on_fields() {
+        let code = r#"
+            #[derive(Serialize)]
+            pub struct Account<T> {
+                #[serde(with = "hex")]
+                #[ts(type = "`0x${string}`")]
+                pub key: Vec<u8>,
+                #[serde_as(as = "DisplayFromStr")]
+                #[ts(as = "String")]
+                pub balance: u128,
+                #[ts(as = "Vec<T>")]
+                pub items: ItemSet<T>,
+            }
+
+            #[derive(Serialize)]
+            pub struct Id(#[ts(type = "string")] u64);
+
+            #[derive(Serialize)]
+            pub enum Change {
+                Set(#[ts(as = "Option<String>")] Value),
+                Move {
+                    #[serde(with = "chrono::serde::ts_seconds")]
+                    #[ts(type = "Date")]
+                    at: DateTime<Utc>,
+                },
+            }
+        "#;
+
+        let (structs, enums) = parse_types(code, &test_path()).unwrap();
+        let fields = &structs[0].fields;
+        assert_eq!(fields[0].ty, RustType::Verbatim("`0x${string}`".to_string()));
+        assert_eq!(fields[1].ty, RustType::Primitive("String".to_string()));
+        assert_eq!(fields[2].ty, RustType::Vec(Box::new(RustType::Generic("T".to_string()))));
+        assert_eq!(structs[1].fields[0].ty, RustType::Verbatim("string".to_string()));
+
+        match &enums[0].variants[0].data {
+            VariantData::Tuple(types) => assert_eq!(
+                types[0],
+                RustType::Option(Box::new(RustType::Primitive("String".to_string())))
+            ),
+            other => panic!("Expected tuple variant, got {:?}", other),
+        }
+        match &enums[0].variants[1].data {
+            VariantData::Struct(fields) => assert_eq!(fields[0].ty, RustType::Verbatim("Date".to_string())),
+            other => panic!("Expected struct variant, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_ts_type_and_as_on_types() {
+        let code = r#"
+            /// Serialized as its Display output
+            #[derive(Serialize)]
+            #[ts(as = "String")]
+            pub struct Version {
+                pub major: u32,
+                pub minor: u32,
+            }
+
+            #[derive(Serialize)]
+            #[ts(type = "'low' | 'high' | number")]
+            pub enum Priority {
+                Low,
+                High,
+                Custom(u8),
+            }
+        "#;
+
+        let (structs, enums) = parse_types(code, &test_path()).unwrap();
+        assert!(enums.is_empty());
+        assert_eq!(structs.len(), 2);
+
+        assert_eq!(structs[0].name, "Version");
+        assert!(structs[0].transparent);
+        assert_eq!(structs[0].fields.len(), 1);
+        assert_eq!(structs[0].fields[0].ty, RustType::Primitive("String".to_string()));
+        assert_eq!(structs[0].docs.lines, vec!["Serialized as its Display output"]);
+
+        assert_eq!(structs[1].name, "Priority");
+        assert_eq!(
+            structs[1].fields[0].ty,
+            RustType::Verbatim("'low' | 'high' | number".to_string())
+        );
+    }
+
+    #[test]
+    fn test_ts_naming_attributes() {
+        let code = r#"
+            #[derive(Serialize)]
+            #[serde(rename_all = "camelCase")]
+            #[ts(rename = "UserDto", rename_all = "snake_case")]
+            pub struct User {
+                #[serde(rename = "kind")]
+                #[ts(rename = "type")]
+                pub user_kind: String,
+                pub display_name: String,
+                #[ts(skip)]
+                pub password_hash: String,
+            }
+
+            #[derive(Serialize)]
+            #[ts(rename_all = "kebab-case")]
+            pub enum Role {
+                #[serde(rename = "admin")]
+                #[ts(rename = "Administrator", rename_all = "PascalCase")]
+                SuperAdmin { granted_by: String },
+                ReadOnly,
+                #[ts(skip)]
+                System,
+            }
+
+            #[derive(Serialize)]
+            #[ts(skip)]
+            pub struct Internal {
+                pub id: u32,
+            }
+        "#;
+
+        let (structs, enums) = parse_types(code, &test_path()).unwrap();
+        assert_eq!(structs.len(), 1);
+
+        let user = &structs[0];
+        assert_eq!(user.name, "User");
+        assert_eq!(user.ts_name.as_deref(), Some("UserDto"));
+        assert_eq!(user.fields[0].name, "type");
+        assert!(user.fields[0].has_explicit_rename);
+        assert_eq!(user.fields[1].name, "display_name");
+        assert_eq!(user.fields[2].presence, FieldPresence::Skipped);
+
+        let role = &enums[0];
+        assert_eq!(role.ts_name, None);
+        let names: Vec<_> = role.variants.iter().map(|v| v.name.as_str()).collect();
+        assert_eq!(names, vec!["Administrator", "read-only"]);
+        match &role.variants[0].data {
+            VariantData::Struct(fields) => assert_eq!(fields[0].name, "GrantedBy"),
+            other => panic!("Expected struct variant, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_ts_export_attribute() {
+        let code = r#"
+            #[derive(Serialize, TS)]
+            #[ts(export)]
+            pub struct Theme {
+                pub dark: bool,
+            }
+
+            #[derive(Serialize, TS)]
+            #[ts(export, as = "String")]
+            pub enum Locale {
+                En,
+            }
+
+            #[derive(Serialize)]
+            pub struct Other {
+                pub id: u32,
+            }
+        "#;
+
+        let (structs, _) = parse_types(code, &test_path()).unwrap();
+        assert!(structs[0].export);
+        assert!(structs[1].export);
+        assert!(!structs[2].export);
+    }
 }
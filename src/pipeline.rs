@@ -3,22 +3,31 @@
 //! Flow: Scan -> Parse -> Resolve -> Collect -> Generate
 
 use anyhow::{Context, Result};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
 
 use crate::cargo_expand::{find_cargo_manifest, run_cargo_expand};
-use crate::config::Config;
+use crate::config::{Config, TypeMapping};
 use crate::generator::{
-    commands_gen::generate_commands_file, types_gen::generate_types_file, GeneratorContext,
+    commands_gen::{calculate_relative_import, generate_commands_file}, events_gen::generate_events_file, types_gen::generate_types_file, GeneratorContext,
+    GENERATED_HEADER,
 };
 use crate::known_types;
-use crate::models::{ParseResult, RustEnum, RustStruct, RustType};
-use crate::parser::{parse_commands, parse_types, parse_types_expanded};
+use crate::models::{
+    FieldPresence, ParseResult, RegisteredCommand, RustEnum, RustStruct, RustType, RustTypeAlias, StructField,
+    TauriCommand,
+};
+use crate::parser::{
+    parse_commands, parse_registered_commands, parse_type_aliases, parse_types,
+    parse_types_expanded,
+};
 use crate::resolver::{ModuleResolver, ResolutionResult};
 use crate::scanner::Scanner;
+use similar::TextDiff;
 
 /// Result of type collection with potential conflicts
+#[derive(Clone)]
 pub struct TypeCollectionResult {
     /// Successfully resolved types: name (as used in code) -> source file
     pub resolved: HashMap<String, PathBuf>,
@@ -31,18 +40,161 @@ pub struct TypeCollectionResult {
     pub alias_to_original: HashMap<String, String>,
 }
 
+/// Parsed types by name and source file
+struct TypeIndex<'a> {
+    structs: HashMap<(&'a str, &'a Path), &'a RustStruct>,
+    enums: HashMap<(&'a str, &'a Path), &'a RustEnum>,
+    aliases: HashMap<(&'a str, &'a Path), &'a RustTypeAlias>,
+}
+
+impl<'a> TypeIndex<'a> {
+    fn new(parse_result: &'a ParseResult) -> Self {
+        Self {
+            structs: parse_result
+                .structs
+                .iter()
+                .map(|s| ((s.name.as_str(), s.source_file.as_path()), s))
+                .collect(),
+            enums: parse_result
+                .enums
+                .iter()
+                .map(|e| ((e.name.as_str(), e.source_file.as_path()), e))
+                .collect(),
+            aliases: parse_result
+                .type_aliases
+                .iter()
+                .map(|a| ((a.name.as_str(), a.source_file.as_path()), a))
+                .collect(),
+        }
+    }
+}
+
+/// A rendered output file and the path it belongs at
+#[derive(Debug, Clone)]
+pub struct GeneratedFile {
+    pub path: PathBuf,
+    pub content: String,
+}
+
+/// Outcome of writing a generated file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WriteStatus {
+    /// The file did not exist
+    Created,
+    /// The file existed with different content
+    Updated,
+    /// The file already had the generated content and was left untouched
+    Unchanged,
+}
+
+impl WriteStatus {
+    fn label(self) -> &'static str {
+        match self {
+            WriteStatus::Created => "Created",
+            WriteStatus::Updated => "Updated",
+            WriteStatus::Unchanged => "Unchanged",
+        }
+    }
+}
+
 /// Main pipeline for code generation
 pub struct Pipeline {
     verbose: bool,
+    /// Fail instead of rendering from the remaining files when some files don't parse
+    fail_on_parse_errors: bool,
 }
 
 impl Pipeline {
     pub fn new(verbose: bool) -> Self {
-        Self { verbose }
+        Self {
+            verbose,
+            fail_on_parse_errors: false,
+        }
+    }
+
+    /// Fail when some source files don't parse, so the output on disk isn't replaced by partial bindings
+    pub fn fail_on_parse_errors(mut self, fail: bool) -> Self {
+        self.fail_on_parse_errors = fail;
+        self
     }
 
     /// Run the full generation pipeline
     pub fn run(&self, config: &Config) -> Result<()> {
+        let files = self.render(config)?;
+        self.write_output(&files)?;
+
+        if let Some(path) = self.stale_events_file(config, &files)? {
+            fs::remove_file(&path)
+                .with_context(|| format!("Failed to remove generated file: {}", path.display()))?;
+            println!("Removed: {}", path.display());
+        }
+
+        println!("Done!");
+
+        Ok(())
+    }
+
+    /// Compare the rendered output with the files on disk without writing anything
+    ///
+    /// Prints a unified diff for every file that differs and returns the stale paths.
+    pub fn check(&self, config: &Config) -> Result<Vec<PathBuf>> {
+        let mut stale = Vec::new();
+        let mut files = self.render(config)?;
+
+        // An events file that is no longer generated should be removed
+        if let Some(path) = self.stale_events_file(config, &files)? {
+            files.push(GeneratedFile { path, content: String::new() });
+        }
+
+        for file in files {
+            let on_disk = match fs::read_to_string(&file.path) {
+                Ok(content) => content,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+                Err(e) => {
+                    return Err(e).with_context(|| {
+                        format!("Failed to read generated file: {}", file.path.display())
+                    })
+                }
+            };
+
+            if on_disk == file.content {
+                if self.verbose {
+                    println!("Up to date: {}", file.path.display());
+                }
+                continue;
+            }
+
+            let path = file.path.display().to_string();
+            let diff = TextDiff::from_lines(&on_disk, &file.content);
+            print!(
+                "{}",
+                diff.unified_diff()
+                    .header(&format!("{} (on disk)", path), &format!("{} (generated)", path))
+            );
+            stale.push(file.path);
+        }
+
+        Ok(stale)
+    }
+
+    /// The events file written by an earlier run, when no event payloads are left to render
+    fn stale_events_file(&self, config: &Config, files: &[GeneratedFile]) -> Result<Option<PathBuf>> {
+        let events_file = config.output.events_file();
+        if files.iter().any(|file| file.path == events_file) {
+            return Ok(None);
+        }
+        match fs::read_to_string(&events_file) {
+            // Leave files we didn't generate alone
+            Ok(content) if content.starts_with(GENERATED_HEADER) => Ok(Some(events_file)),
+            Ok(_) => Ok(None),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e)
+                .with_context(|| format!("Failed to read generated file: {}", events_file.display())),
+        }
+    }
+
+    /// Run the pipeline up to output and return the rendered files
+    pub fn render(&self, config: &Config) -> Result<Vec<GeneratedFile>> {
         if self.verbose {
             println!("Scanning directory: {}", config.input.source_dir.display());
         }
@@ -64,11 +216,29 @@ impl Pipeline {
         // Step 2: Parse all files and build resolver
         let (mut parse_result, resolver) = self.parse_files(&rust_files, config, expanded_code.as_deref())?;
 
+        // Step 2.2: Apply [types] mappings and tell known std/ecosystem types apart
+        // from project types by import path
+        self.resolve_known_types(&mut parse_result, &config.types, &resolver);
+
+        // Step 2.21: Take the error type of `Result<T>` returns from the crate's `Result` alias
+        self.resolve_result_aliases(&mut parse_result, &resolver);
+
+        // Step 2.25: Optionally keep only commands registered in generate_handler!
+        if config.input.only_registered_commands {
+            self.filter_registered_commands(&mut parse_result, &resolver);
+        }
+
+        // Step 2.3: Detect plugin commands
+        self.assign_plugins(&mut parse_result, config, &resolver);
+
+        // Step 2.4: Detect raw binary IPC (tauri::ipc::Request bodies, tauri::ipc::Response returns)
+        self.detect_raw_ipc(&mut parse_result.commands, &resolver);
+
         // Step 2.5: Filter out Tauri special types (State, Window, etc.) including aliases
-        self.filter_tauri_special_args(&mut parse_result.commands, &resolver);
+        self.filter_tauri_special_args(&mut parse_result.commands, &config.input.injected_types, &resolver);
 
         // Step 3: Collect and resolve types used in commands
-        let type_collection = self.collect_used_types(&parse_result, &resolver);
+        let type_collection = self.collect_used_types(&parse_result, &resolver, config.output.export_all_serializable);
 
         // Step 4: Check for conflicts
         if !type_collection.conflicts.is_empty() {
@@ -105,23 +275,35 @@ impl Pipeline {
         }
 
         // Step 5: Filter types based on resolution (including aliased types)
-        let (filtered_structs, filtered_enums) =
+        let (mut filtered_structs, mut filtered_enums) =
             self.filter_types(&parse_result, &type_collection);
+        let filtered_aliases = self.filter_type_aliases(&parse_result, &type_collection);
+
+        // Step 5.5: #[serde(default)] fields may only be left out by the frontend
+        self.resolve_input_defaults(
+            &parse_result.commands,
+            &mut filtered_structs,
+            &mut filtered_enums,
+            &filtered_aliases,
+        );
 
         // Summary
         println!(
-            "Parsed {} commands, {} structs (used), {} enums (used)",
+            "Parsed {} commands, {} structs (used), {} enums (used), {} type aliases (used)",
             parse_result.commands.len(),
             filtered_structs.len(),
-            filtered_enums.len()
+            filtered_enums.len(),
+            filtered_aliases.len()
         );
 
         // Step 6: Generate TypeScript files
-        self.generate_output(config, &parse_result, &filtered_structs, &filtered_enums)?;
-
-        println!("Done!");
-
-        Ok(())
+        Ok(self.generate_output(
+            config,
+            &parse_result,
+            &filtered_structs,
+            &filtered_enums,
+            &filtered_aliases,
+        ))
     }
 
     /// Step 1: Scan for Rust files
@@ -219,11 +401,20 @@ impl Pipeline {
                     );
                 }
             }
+
+            if let Ok(aliases) = parse_type_aliases(code, &expanded_path) {
+                for alias in &aliases {
+                    resolver.register_expanded_type(&alias.name, &expanded_path);
+                }
+                parse_result.type_aliases.extend(aliases);
+            }
         }
 
+        let mut parse_failures = 0;
         for file_path in rust_files {
             let content = fs::read_to_string(file_path)
                 .with_context(|| format!("Failed to read file: {}", file_path.display()))?;
+            let mut parsed = true;
 
             // Build resolver scope for this file
             if let Err(e) = resolver.parse_file(file_path, &content, &base_path) {
@@ -249,6 +440,7 @@ impl Pipeline {
                     parse_result.commands.extend(commands);
                 }
                 Err(e) => {
+                    parsed = false;
                     eprintln!(
                         "Warning: Failed to parse commands in {}: {}",
                         file_path.display(),
@@ -272,6 +464,7 @@ impl Pipeline {
                     parse_result.enums.extend(enums);
                 }
                 Err(e) => {
+                    parsed = false;
                     eprintln!(
                         "Warning: Failed to parse types in {}: {}",
                         file_path.display(),
@@ -279,67 +472,205 @@ impl Pipeline {
                     );
                 }
             }
+            if !parsed {
+                parse_failures += 1;
+            }
+
+            // Parse type aliases (syntax errors were already reported above)
+            if let Ok(aliases) = parse_type_aliases(&content, file_path) {
+                parse_result.type_aliases.extend(aliases);
+            }
+
+            if let Ok(registered) = parse_registered_commands(&content, file_path) {
+                parse_result.registered_commands.extend(registered);
+            }
+        }
+
+        if parse_failures > 0 && self.fail_on_parse_errors {
+            anyhow::bail!(
+                "{} file(s) could not be parsed; keeping the previously generated files",
+                parse_failures
+            );
         }
 
         Ok((parse_result, resolver))
     }
 
     /// Step 3: Collect all types used in commands, resolving their source files
+    ///
+    /// Event payloads, `#[ts(export)]` types and, with `export_all`, every serializable type
+    /// are collected as well, along with the types they use.
     fn collect_used_types(
         &self,
         parse_result: &ParseResult,
         resolver: &ModuleResolver,
+        export_all: bool,
     ) -> TypeCollectionResult {
-        let mut resolved_types: HashMap<String, PathBuf> = HashMap::new();
-        let mut conflicts: HashMap<String, Vec<PathBuf>> = HashMap::new();
-        let mut unresolved_types: HashMap<String, PathBuf> = HashMap::new();
-        let mut alias_mappings: HashMap<String, String> = HashMap::new();
-
-        // Build lookup maps: (name, source_file) -> type
-        let struct_by_file: HashMap<(&str, &Path), &RustStruct> = parse_result
-            .structs
-            .iter()
-            .map(|s| ((s.name.as_str(), s.source_file.as_path()), s))
-            .collect();
-        let enum_by_file: HashMap<(&str, &Path), &RustEnum> = parse_result
-            .enums
-            .iter()
-            .map(|e| ((e.name.as_str(), e.source_file.as_path()), e))
-            .collect();
+        let index = TypeIndex::new(parse_result);
+        let mut collection = TypeCollectionResult {
+            resolved: HashMap::new(),
+            conflicts: HashMap::new(),
+            unresolved: HashMap::new(),
+            alias_to_original: HashMap::new(),
+        };
 
         // Collect types from all commands, resolving source files
         for cmd in &parse_result.commands {
             let cmd_file = &cmd.source_file;
 
-            for arg in &cmd.args {
-                self.collect_types_with_resolver(
-                    &arg.ty,
-                    cmd_file,
-                    resolver,
-                    &mut resolved_types,
-                    &mut conflicts,
-                    &mut unresolved_types,
-                    &mut alias_mappings,
-                );
-            }
-            if let Some(ref ret_type) = cmd.return_type {
+            // An error type taken from a `Result` alias is named in the alias's file
+            let alias_error = match &cmd.return_type {
+                Some(RustType::Result { ok, err: Some(err) }) => result_alias(cmd, &parse_result.type_aliases, resolver)
+                    .filter(|(_, error)| *error == Some(err.as_ref()))
+                    .map(|(alias, _)| (ok.as_ref(), err.as_ref(), alias.source_file.as_path())),
+                _ => None,
+            };
+            let returned = match alias_error {
+                Some((ok, err, alias_file)) => vec![(ok, cmd_file.as_path()), (err, alias_file)],
+                None => cmd.return_type.iter().map(|ty| (ty, cmd_file.as_path())).collect(),
+            };
+
+            for (ty, from_file) in cmd.args.iter().map(|arg| (&arg.ty, cmd_file.as_path())).chain(returned) {
                 self.collect_types_with_resolver(
-                    ret_type,
-                    cmd_file,
+                    ty,
+                    from_file,
                     resolver,
-                    &mut resolved_types,
-                    &mut conflicts,
-                    &mut unresolved_types,
-                    &mut alias_mappings,
+                    &mut collection.resolved,
+                    &mut collection.conflicts,
+                    &mut collection.unresolved,
+                    &mut collection.alias_to_original,
                 );
             }
         }
 
+        // Event payloads and exported types are used by the frontend even when no command mentions them
+        let root_types = parse_result
+            .structs
+            .iter()
+            .filter(|s| s.export || s.event.is_some())
+            .map(|s| (&s.name, &s.source_file))
+            .chain(
+                parse_result
+                    .enums
+                    .iter()
+                    .filter(|e| e.export || e.event.is_some())
+                    .map(|e| (&e.name, &e.source_file)),
+            );
+        for (name, source_file) in root_types {
+            self.collect_types_with_resolver(
+                &RustType::Custom(name.clone()),
+                source_file,
+                resolver,
+                &mut collection.resolved,
+                &mut collection.conflicts,
+                &mut collection.unresolved,
+                &mut collection.alias_to_original,
+            );
+        }
+
         // Recursively add nested types
-        let mut to_process: Vec<(String, PathBuf)> = resolved_types
+        let to_process = collection
+            .resolved
             .iter()
             .map(|(name, path)| (name.clone(), path.clone()))
             .collect();
+        self.collect_nested_types(&index, resolver, &mut collection, to_process);
+
+        if export_all {
+            self.collect_all_serializable(parse_result, &index, resolver, &mut collection);
+        }
+
+        collection
+    }
+
+    /// Step 3 (export_all): Collect every remaining serializable type, with the types it uses
+    ///
+    /// Types whose name is already taken by a used type, or shared by several unused types,
+    /// are skipped with a warning instead of failing as name conflicts.
+    fn collect_all_serializable(
+        &self,
+        parse_result: &ParseResult,
+        index: &TypeIndex,
+        resolver: &ModuleResolver,
+        collection: &mut TypeCollectionResult,
+    ) {
+        let mut candidates: BTreeMap<&str, Vec<&Path>> = BTreeMap::new();
+        let all_types = parse_result
+            .structs
+            .iter()
+            .map(|s| (&s.name, &s.source_file))
+            .chain(parse_result.enums.iter().map(|e| (&e.name, &e.source_file)));
+        for (name, source_file) in all_types {
+            candidates.entry(name.as_str()).or_default().push(source_file.as_path());
+        }
+
+        for (name, mut files) in candidates {
+            files.sort();
+            files.dedup();
+            if let Some(used) = collection.resolved.get(name) {
+                for file in files.iter().filter(|file| **file != used.as_path()) {
+                    eprintln!(
+                        "Warning: Not exporting '{}' from {}: the name is already used by {}",
+                        name,
+                        file.display(),
+                        used.display()
+                    );
+                }
+                continue;
+            }
+            if files.len() > 1 {
+                let files: Vec<_> = files.iter().map(|file| file.display().to_string()).collect();
+                eprintln!(
+                    "Warning: Not exporting '{}': the name is defined in several files ({})",
+                    name,
+                    files.join(", ")
+                );
+                continue;
+            }
+
+            // Keep the type only if it and the types it uses don't clash with collected ones
+            let mut attempt = collection.clone();
+            self.collect_types_with_resolver(
+                &RustType::Custom(name.to_string()),
+                files[0],
+                resolver,
+                &mut attempt.resolved,
+                &mut attempt.conflicts,
+                &mut attempt.unresolved,
+                &mut attempt.alias_to_original,
+            );
+            let to_process = attempt
+                .resolved
+                .iter()
+                .filter(|(name, _)| !collection.resolved.contains_key(*name))
+                .map(|(name, path)| (name.clone(), path.clone()))
+                .collect();
+            self.collect_nested_types(index, resolver, &mut attempt, to_process);
+
+            if attempt.conflicts != collection.conflicts {
+                eprintln!(
+                    "Warning: Not exporting '{}' from {}: it uses a type whose name is already taken",
+                    name,
+                    files[0].display()
+                );
+            } else {
+                *collection = attempt;
+            }
+        }
+    }
+
+    /// Collect the types used by the fields of `to_process` types, recursively
+    fn collect_nested_types(
+        &self,
+        index: &TypeIndex,
+        resolver: &ModuleResolver,
+        collection: &mut TypeCollectionResult,
+        mut to_process: Vec<(String, PathBuf)>,
+    ) {
+        let resolved_types = &mut collection.resolved;
+        let conflicts = &mut collection.conflicts;
+        let unresolved_types = &mut collection.unresolved;
+        let alias_mappings = &mut collection.alias_to_original;
         let mut processed: HashSet<(String, PathBuf)> = HashSet::new();
 
         while let Some((type_name, type_file)) = to_process.pop() {
@@ -387,8 +718,9 @@ impl Pipeline {
             };
 
             // Check if it's a struct in this file
-            if let Some(s) = struct_by_file.get(&(type_name.as_str(), type_file.as_path())) {
-                for field in &s.fields {
+            if let Some(s) = index.structs.get(&(type_name.as_str(), type_file.as_path())) {
+                // Skipped fields never reach the frontend, so their types aren't needed
+                for field in s.fields.iter().filter(|f| f.is_serialized()) {
                     for t in collect_custom_types_from_rust_type(&field.ty) {
                         process_nested_type(t);
                     }
@@ -396,7 +728,7 @@ impl Pipeline {
             }
 
             // Check if it's an enum in this file
-            if let Some(e) = enum_by_file.get(&(type_name.as_str(), type_file.as_path())) {
+            if let Some(e) = index.enums.get(&(type_name.as_str(), type_file.as_path())) {
                 for variant in &e.variants {
                     let nested_names = match &variant.data {
                         crate::models::VariantData::Unit => vec![],
@@ -406,6 +738,7 @@ impl Pipeline {
                             .collect(),
                         crate::models::VariantData::Struct(fields) => fields
                             .iter()
+                            .filter(|f| f.is_serialized())
                             .flat_map(|f| collect_custom_types_from_rust_type(&f.ty))
                             .collect(),
                     };
@@ -414,13 +747,13 @@ impl Pipeline {
                     }
                 }
             }
-        }
 
-        TypeCollectionResult {
-            resolved: resolved_types,
-            conflicts,
-            unresolved: unresolved_types,
-            alias_to_original: alias_mappings,
+            // Check if it's a type alias in this file
+            if let Some(a) = index.aliases.get(&(type_name.as_str(), type_file.as_path())) {
+                for t in collect_custom_types_from_rust_type(&a.target) {
+                    process_nested_type(t);
+                }
+            }
         }
     }
 
@@ -474,14 +807,25 @@ impl Pipeline {
                     }
                 }
             }
-            RustType::Vec(inner) => {
+            RustType::CustomGeneric { name, args } => {
+                // Resolve the generic type itself, then each of its type arguments
+                let base = RustType::Custom(name.clone());
+                self.collect_types_with_resolver(&base, from_file, resolver, resolved, conflicts, unresolved, alias_mappings);
+                for arg in args {
+                    self.collect_types_with_resolver(arg, from_file, resolver, resolved, conflicts, unresolved, alias_mappings);
+                }
+            }
+            RustType::Vec(inner) | RustType::Array { elem: inner, .. } => {
                 self.collect_types_with_resolver(inner, from_file, resolver, resolved, conflicts, unresolved, alias_mappings)
             }
-            RustType::Option(inner) => {
+            RustType::Option(inner) | RustType::Channel(inner) => {
                 self.collect_types_with_resolver(inner, from_file, resolver, resolved, conflicts, unresolved, alias_mappings)
             }
-            RustType::Result(ok) => {
-                self.collect_types_with_resolver(ok, from_file, resolver, resolved, conflicts, unresolved, alias_mappings)
+            RustType::Result { ok, err } => {
+                self.collect_types_with_resolver(ok, from_file, resolver, resolved, conflicts, unresolved, alias_mappings);
+                if let Some(err) = err {
+                    self.collect_types_with_resolver(err, from_file, resolver, resolved, conflicts, unresolved, alias_mappings);
+                }
             }
             RustType::HashMap { key, value } => {
                 self.collect_types_with_resolver(key, from_file, resolver, resolved, conflicts, unresolved, alias_mappings);
@@ -574,43 +918,133 @@ impl Pipeline {
         (filtered_structs, filtered_enums)
     }
 
-    /// Step 6: Generate TypeScript output files
+    /// Step 5 (aliases): Filter type aliases based on resolved types
+    fn filter_type_aliases(
+        &self,
+        parse_result: &ParseResult,
+        type_collection: &TypeCollectionResult,
+    ) -> Vec<RustTypeAlias> {
+        let mut filtered: Vec<RustTypeAlias> = Vec::new();
+        let mut seen_names: HashSet<String> = HashSet::new();
+
+        for a in &parse_result.type_aliases {
+            if seen_names.contains(&a.name) {
+                continue;
+            }
+
+            // Only include if this specific alias (by name AND source file) was resolved
+            if let Some(resolved_file) = type_collection.resolved.get(&a.name) {
+                if &a.source_file == resolved_file {
+                    seen_names.insert(a.name.clone());
+                    filtered.push(a.clone());
+                }
+            }
+        }
+
+        filtered
+    }
+
+    /// Step 5.5: Make #[serde(default)] fields optional in types sent as command arguments
+    ///
+    /// serde fills in missing defaulted fields when deserializing, but always writes them when
+    /// serializing, so types the frontend only receives keep them required.
+    fn resolve_input_defaults(
+        &self,
+        commands: &[TauriCommand],
+        structs: &mut [RustStruct],
+        enums: &mut [RustEnum],
+        aliases: &[RustTypeAlias],
+    ) {
+        // Channel<T> payloads flow from Rust to the frontend, so they aren't inputs
+        let mut to_process: Vec<String> = commands
+            .iter()
+            .flat_map(|cmd| &cmd.args)
+            .filter(|arg| !matches!(arg.ty, RustType::Channel(_)))
+            .flat_map(|arg| collect_custom_types_from_rust_type(&arg.ty))
+            .collect();
+        let mut input_types: HashSet<String> = HashSet::new();
+
+        while let Some(type_name) = to_process.pop() {
+            let simple_name = type_name.split("::").last().unwrap_or(&type_name).to_string();
+            if !input_types.insert(simple_name.clone()) {
+                continue;
+            }
+
+            let mut nested = Vec::new();
+            if let Some(s) = structs.iter().find(|s| s.name == simple_name) {
+                nested.extend(s.fields.iter().filter(|f| f.is_serialized()).map(|f| &f.ty));
+            }
+            if let Some(e) = enums.iter().find(|e| e.name == simple_name) {
+                for variant in &e.variants {
+                    match &variant.data {
+                        crate::models::VariantData::Unit => {}
+                        crate::models::VariantData::Tuple(types) => nested.extend(types),
+                        crate::models::VariantData::Struct(fields) => {
+                            nested.extend(fields.iter().filter(|f| f.is_serialized()).map(|f| &f.ty))
+                        }
+                    }
+                }
+            }
+            if let Some(a) = aliases.iter().find(|a| a.name == simple_name) {
+                nested.push(&a.target);
+            }
+            to_process.extend(nested.into_iter().flat_map(collect_custom_types_from_rust_type));
+        }
+
+        let make_optional = |fields: &mut [StructField]| {
+            for field in fields.iter_mut().filter(|f| f.presence == FieldPresence::DefaultOnInput) {
+                field.presence = FieldPresence::Optional;
+            }
+        };
+        for s in structs.iter_mut().filter(|s| input_types.contains(&s.name)) {
+            make_optional(&mut s.fields);
+        }
+        for e in enums.iter_mut().filter(|e| input_types.contains(&e.name)) {
+            for variant in &mut e.variants {
+                if let crate::models::VariantData::Struct(fields) = &mut variant.data {
+                    make_optional(fields);
+                }
+            }
+        }
+    }
+
+    /// Step 6: Render TypeScript output files
     fn generate_output(
         &self,
         config: &Config,
         parse_result: &ParseResult,
         filtered_structs: &[RustStruct],
         filtered_enums: &[RustEnum],
-    ) -> Result<()> {
+        filtered_aliases: &[RustTypeAlias],
+    ) -> Vec<GeneratedFile> {
         // Create generator context
         let mut ctx = GeneratorContext::new(config.naming.clone());
+        ctx.max_array_tuple_len = config.output.max_array_tuple_len;
+        ctx.error_handling = config.output.error_handling;
 
         for s in filtered_structs {
             ctx.register_type(&s.name);
+            if let Some(ts_name) = &s.ts_name {
+                ctx.rename_type(&s.name, ts_name);
+            }
         }
         for e in filtered_enums {
             ctx.register_type(&e.name);
-        }
-
-        // Generate types.ts
-        let types_content = generate_types_file(filtered_structs, filtered_enums, &ctx);
-
-        if let Some(parent) = config.output.types_file.parent() {
-            if !parent.exists() {
-                fs::create_dir_all(parent)?;
+            if let Some(ts_name) = &e.ts_name {
+                ctx.rename_type(&e.name, ts_name);
             }
         }
+        for a in filtered_aliases {
+            ctx.register_type(&a.name);
+        }
 
-        fs::write(&config.output.types_file, &types_content).with_context(|| {
-            format!(
-                "Failed to write types file: {}",
-                config.output.types_file.display()
-            )
-        })?;
-
-        println!("Generated: {}", config.output.types_file.display());
+        // Generate types.ts
+        ctx.type_mappings = mappings_for_file(&config.types, &config.output.types_file);
+        let types_content =
+            generate_types_file(filtered_structs, filtered_enums, filtered_aliases, &ctx);
 
         // Generate commands.ts
+        ctx.type_mappings = mappings_for_file(&config.types, &config.output.commands_file);
         let commands_content = generate_commands_file(
             &parse_result.commands,
             &config.output.types_file,
@@ -618,63 +1052,580 @@ impl Pipeline {
             &ctx,
         );
 
-        if let Some(parent) = config.output.commands_file.parent() {
-            if !parent.exists() {
-                fs::create_dir_all(parent)?;
-            }
+        let mut files = vec![
+            GeneratedFile {
+                path: config.output.types_file.clone(),
+                content: types_content,
+            },
+            GeneratedFile {
+                path: config.output.commands_file.clone(),
+                content: commands_content,
+            },
+        ];
+
+        // Generate events.ts, only when some type is an event payload
+        let events_file = config.output.events_file();
+        ctx.type_mappings = mappings_for_file(&config.types, &events_file);
+        if let Some(events_content) = generate_events_file(
+            filtered_structs,
+            filtered_enums,
+            &config.output.types_file,
+            &events_file,
+            &ctx,
+        ) {
+            files.push(GeneratedFile {
+                path: events_file,
+                content: events_content,
+            });
         }
 
-        fs::write(&config.output.commands_file, &commands_content).with_context(|| {
-            format!(
-                "Failed to write commands file: {}",
-                config.output.commands_file.display()
-            )
-        })?;
+        files
+    }
 
-        println!("Generated: {}", config.output.commands_file.display());
+    /// Step 7: Write rendered files to disk, skipping files whose content is unchanged
+    fn write_output(&self, files: &[GeneratedFile]) -> Result<()> {
+        for file in files {
+            let status = write_generated_file(file)?;
+            println!("{}: {}", status.label(), file.path.display());
+        }
 
         Ok(())
     }
-    
-    /// Step 2.5: Filter out Tauri special types from command arguments
+
+    /// Step 2.2: Qualify type names through each file's imports to apply `[types]` mappings
+    /// and resolve known-type names (`Duration`, `Date`, ...)
+    ///
+    /// Known-type names resolving to a project type become custom types; the rest are mapped
+    /// to their canonical `crate::Name` path in the known-type table. `tauri::ipc::Channel<T>`
+    /// becomes a frontend channel.
+    fn resolve_known_types(
+        &self,
+        parse_result: &mut ParseResult,
+        mappings: &BTreeMap<String, TypeMapping>,
+        resolver: &ModuleResolver,
+    ) {
+        for cmd in &mut parse_result.commands {
+            for arg in &mut cmd.args {
+                resolve_known_type(&mut arg.ty, &cmd.source_file, mappings, resolver);
+            }
+            if let Some(ty) = &mut cmd.return_type {
+                resolve_known_type(ty, &cmd.source_file, mappings, resolver);
+            }
+        }
+        for s in &mut parse_result.structs {
+            for field in &mut s.fields {
+                resolve_known_type(&mut field.ty, &s.source_file, mappings, resolver);
+            }
+        }
+        for e in &mut parse_result.enums {
+            for variant in &mut e.variants {
+                match &mut variant.data {
+                    crate::models::VariantData::Unit => {}
+                    crate::models::VariantData::Tuple(types) => {
+                        for ty in types {
+                            resolve_known_type(ty, &e.source_file, mappings, resolver);
+                        }
+                    }
+                    crate::models::VariantData::Struct(fields) => {
+                        for field in fields {
+                            resolve_known_type(&mut field.ty, &e.source_file, mappings, resolver);
+                        }
+                    }
+                }
+            }
+        }
+        for a in &mut parse_result.type_aliases {
+            resolve_known_type(&mut a.target, &a.source_file, mappings, resolver);
+        }
+    }
+
+    /// Step 2.21: Fill in the error type of commands returning a single-argument `Result<T>`
+    ///
+    /// `Result<T>` can only be a crate alias like `pub type Result<T> = std::result::Result<T, Error>;`,
+    /// so the error type is the one of the alias the command's file refers to.
+    fn resolve_result_aliases(&self, parse_result: &mut ParseResult, resolver: &ModuleResolver) {
+        let errors: Vec<_> = parse_result
+            .commands
+            .iter()
+            .map(|cmd| match &cmd.return_type {
+                Some(RustType::Result { err: None, .. }) => result_alias(cmd, &parse_result.type_aliases, resolver)
+                    .and_then(|(_, err)| err)
+                    .cloned(),
+                _ => None,
+            })
+            .collect();
+
+        for (cmd, error) in parse_result.commands.iter_mut().zip(errors) {
+            if let (Some(RustType::Result { err, .. }), Some(error)) = (&mut cmd.return_type, error) {
+                *err = Some(Box::new(error));
+            }
+        }
+    }
+
+    /// Step 2.25: Drop commands that are not listed in any generate_handler! invocation
+    fn filter_registered_commands(&self, parse_result: &mut ParseResult, resolver: &ModuleResolver) {
+        let registrations = &parse_result.registered_commands;
+        if registrations.is_empty() {
+            eprintln!(
+                "Warning: only_registered_commands is enabled but no generate_handler! invocation was found; generating all commands"
+            );
+            return;
+        }
+
+        let mut registered = vec![false; parse_result.commands.len()];
+        let mut unmatched: Vec<&RegisteredCommand> = Vec::new();
+
+        let matches = match_registrations(&parse_result.commands, registrations, resolver);
+        for (registration, matched) in registrations.iter().zip(matches) {
+            match matched {
+                Some(index) => registered[index] = true,
+                None => unmatched.push(registration),
+            }
+        }
+
+        for registration in unmatched {
+            eprintln!(
+                "Warning: Command '{}' is registered in generate_handler! ({}) but its definition was not found",
+                registration.path.join("::"),
+                registration.source_file.display()
+            );
+        }
+
+        let mut index = 0;
+        parse_result.commands.retain(|cmd| {
+            let keep = registered[index];
+            index += 1;
+            if !keep {
+                eprintln!(
+                    "Warning: Command '{}' in {} is not registered in generate_handler! and will not be generated",
+                    cmd.name,
+                    cmd.source_file.display()
+                );
+            }
+            keep
+        });
+    }
+
+    /// Step 2.3: Mark commands exposed by Tauri plugins
+    ///
+    /// Commands under a directory configured in `[input.plugins]` belong to that plugin;
+    /// otherwise the plugin is taken from the `plugin::Builder` whose handler registers them.
+    fn assign_plugins(&self, parse_result: &mut ParseResult, config: &Config, resolver: &ModuleResolver) {
+        let matches = match_registrations(
+            &parse_result.commands,
+            &parse_result.registered_commands,
+            resolver,
+        );
+        for (registration, matched) in parse_result.registered_commands.iter().zip(matches) {
+            if let (Some(plugin), Some(index)) = (&registration.plugin, matched) {
+                parse_result.commands[index].plugin = Some(plugin.clone());
+            }
+        }
+
+        // Compare absolute paths, so `./src/plugins/fs` matches files scanned as `src/plugins/fs/...`
+        let plugin_dirs: Vec<_> = config
+            .input
+            .plugins
+            .iter()
+            .map(|(name, dir)| (name, normalize_path(dir)))
+            .collect();
+        let mut matched = vec![false; plugin_dirs.len()];
+        for cmd in &mut parse_result.commands {
+            let source_file = normalize_path(&cmd.source_file);
+            if let Some(index) = plugin_dirs.iter().position(|(_, dir)| source_file.starts_with(dir)) {
+                cmd.plugin = Some(plugin_dirs[index].0.clone());
+                matched[index] = true;
+            }
+        }
+
+        for ((name, dir), matched) in plugin_dirs.iter().zip(matched) {
+            if !matched {
+                eprintln!(
+                    "Warning: plugin '{}' matches no commands; no command was found under {}",
+                    name,
+                    dir.display()
+                );
+            }
+        }
+    }
+
+    /// Step 2.4: Mark commands reading a raw request body and map raw responses to `ArrayBuffer`
+    fn detect_raw_ipc(&self, commands: &mut [TauriCommand], resolver: &ModuleResolver) {
+        let type_path = |ty: &RustType, file: &Path| match ty {
+            RustType::Custom(name) | RustType::CustomGeneric { name, .. } => {
+                Some(qualify_type_path(name, file, resolver))
+            }
+            _ => None,
+        };
+
+        for cmd in commands.iter_mut() {
+            cmd.raw_body = cmd.args.iter().any(|arg| {
+                type_path(&arg.ty, &cmd.source_file).is_some_and(|path| known_types::is_raw_request_type(&path))
+            });
+
+            let response = match &mut cmd.return_type {
+                Some(RustType::Result { ok, .. }) => Some(ok.as_mut()),
+                other => other.as_mut(),
+            };
+            if let Some(response) = response {
+                if type_path(response, &cmd.source_file).is_some_and(|path| known_types::is_raw_response_type(&path)) {
+                    *response = RustType::Binary;
+                }
+            }
+        }
+    }
+
+    /// Step 2.5: Filter out framework-injected types (State, AppHandle, `injected_types`, ...) from command arguments
     /// This handles both direct uses (State<T>) and type aliases (type MyState = State<T>)
     fn filter_tauri_special_args(
         &self,
         commands: &mut [crate::models::TauriCommand],
+        injected_types: &[String],
         resolver: &ModuleResolver,
     ) {
         for cmd in commands.iter_mut() {
             cmd.args.retain(|arg| {
-                // Get the base type name from the RustType
-                let type_name = match &arg.ty {
-                    RustType::Custom(name) => {
-                        // Extract just the type name (without path)
-                        name.split("::").last().unwrap_or(name).to_string()
-                    }
-                    RustType::Unknown(name) => {
-                        name.split("::").last().unwrap_or(name).to_string()
-                    }
+                let name = match &arg.ty {
+                    RustType::Custom(name) | RustType::CustomGeneric { name, .. } => name,
+                    RustType::Unknown(name) => name,
                     _ => return true, // Keep primitives, Vec, Option, etc.
                 };
-                
-                // Check if it's directly a Tauri special type
-                if known_types::is_tauri_special_type(&type_name) {
+
+                // Check the type itself, qualified through the command file's imports
+                let path = qualify_type_path(name, &cmd.source_file, resolver);
+                if known_types::is_injected_type(&path, injected_types) {
                     return false;
                 }
-                
-                // Check if it's an alias to a Tauri special type
-                if let Some(target) = resolver.resolve_alias_target(&type_name, &cmd.source_file) {
-                    if known_types::is_tauri_special_type(&target) {
+
+                // Check if it's an alias to an injected type
+                let type_name = name.split("::").last().unwrap_or(name);
+                if let Some(target) = resolver.resolve_alias_target(type_name, &cmd.source_file) {
+                    if known_types::is_injected_type(&[target], injected_types) {
                         return false;
                     }
                 }
-                
+
                 true
             });
         }
     }
 }
 
+/// Module path of a scanned file (e.g. `["crate", "commands"]`)
+fn module_path_of(file: &Path, resolver: &ModuleResolver) -> Vec<String> {
+    resolver
+        .files
+        .get(file)
+        .map(|scope| scope.module_path.clone())
+        .unwrap_or_else(|| vec!["crate".to_string()])
+}
+
+/// Find the command each generate_handler! registration refers to
+///
+/// Registrations are matched by their full module path. A registration that
+/// doesn't match exactly (e.g. a re-export) falls back to a unique command name.
+fn match_registrations(
+    commands: &[TauriCommand],
+    registrations: &[RegisteredCommand],
+    resolver: &ModuleResolver,
+) -> Vec<Option<usize>> {
+    let command_paths: Vec<Vec<String>> = commands
+        .iter()
+        .map(|cmd| {
+            let mut path = module_path_of(&cmd.source_file, resolver);
+            path.push(cmd.name.clone());
+            path
+        })
+        .collect();
+
+    registrations
+        .iter()
+        .map(|registration| {
+            let full_path = resolve_registration(registration, resolver);
+            if let Some(index) = command_paths.iter().position(|p| *p == full_path) {
+                return Some(index);
+            }
+
+            let name = registration.path.last().map(String::as_str).unwrap_or_default();
+            let mut candidates = commands
+                .iter()
+                .enumerate()
+                .filter(|(_, cmd)| cmd.name == name)
+                .map(|(index, _)| index);
+            match (candidates.next(), candidates.next()) {
+                (Some(index), None) => Some(index),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Resolve a generate_handler! path to an absolute module path, following `use` imports
+fn resolve_registration(registration: &RegisteredCommand, resolver: &ModuleResolver) -> Vec<String> {
+    let module = module_path_of(&registration.source_file, resolver);
+
+    let path = match registration.path.first().map(String::as_str) {
+        Some("crate" | "self" | "super") | None => registration.path.clone(),
+        Some(first) => match resolver
+            .files
+            .get(&registration.source_file)
+            .and_then(|scope| scope.imports.get(first))
+        {
+            // `use commands::user;` + `user::get_user`
+            Some(import) => import
+                .path
+                .iter()
+                .chain(registration.path.iter().skip(1))
+                .cloned()
+                .collect(),
+            None => registration.path.clone(),
+        },
+    };
+
+    absolute_module_path(&module, &path)
+}
+
+/// Qualify a type path as written in `file` (e.g. `State` with `use tauri::State;` -> `tauri::State`)
+///
+/// Project types become `crate::...` paths. A bare name whose origin is unknown is returned as is.
+/// The `Result` alias with a single type parameter visible from a command's file, and its error type
+fn result_alias<'a>(
+    cmd: &TauriCommand,
+    aliases: &'a [RustTypeAlias],
+    resolver: &ModuleResolver,
+) -> Option<(&'a RustTypeAlias, Option<&'a RustType>)> {
+    let ResolutionResult::Found(source) = resolver.resolve_type("Result", &cmd.source_file) else {
+        return None;
+    };
+    let alias = aliases
+        .iter()
+        .find(|a| a.name == "Result" && a.source_file == source && a.generics.len() == 1)?;
+    let error = match &alias.target {
+        RustType::Result { err: Some(err), .. } if !matches!(err.as_ref(), RustType::Generic(_)) => Some(err.as_ref()),
+        _ => None,
+    };
+    Some((alias, error))
+}
+
+fn qualify_type_path(name: &str, file: &Path, resolver: &ModuleResolver) -> Vec<String> {
+    let path: Vec<String> = name.split("::").map(String::from).collect();
+    let Some(scope) = resolver.files.get(file) else {
+        return path;
+    };
+
+    let first = path[0].as_str();
+    if matches!(first, "crate" | "self" | "super") {
+        return absolute_module_path(&scope.module_path, &path);
+    }
+    if let Some(import) = scope.imports.get(first) {
+        let imported: Vec<String> = import.path.iter().chain(path.iter().skip(1)).cloned().collect();
+        let mut child_module = scope.module_path.clone();
+        child_module.push(imported[0].clone());
+        // `use auth::Session;` may name a child module rather than an external crate
+        let is_relative = matches!(imported[0].as_str(), "crate" | "self" | "super")
+            || resolver.module_to_file.contains_key(&child_module);
+        return if is_relative {
+            absolute_module_path(&scope.module_path, &imported)
+        } else {
+            imported
+        };
+    }
+    if path.len() == 1 {
+        // Defined in this file, or reachable through a glob import
+        if let ResolutionResult::Found(source) = resolver.resolve_type(name, file) {
+            let mut qualified = module_path_of(&source, resolver);
+            qualified.push(name.to_string());
+            return qualified;
+        }
+    }
+
+    path
+}
+
+/// Apply `[types]` mappings and qualify known-type names in `ty` (recursively) as written in `file`
+fn resolve_known_type(
+    ty: &mut RustType,
+    file: &Path,
+    mappings: &BTreeMap<String, TypeMapping>,
+    resolver: &ModuleResolver,
+) {
+    // Mappings take precedence over built-in and project types
+    if let RustType::Primitive(name) | RustType::Custom(name) | RustType::CustomGeneric { name, .. } = ty {
+        let path = qualify_type_path(name, file, resolver);
+        if let Some(key) = known_types::find_type_mapping(&path, mappings.keys()) {
+            *ty = RustType::Mapped(key.clone());
+            return;
+        }
+    }
+
+    match ty {
+        RustType::Primitive(name) if !known_types::is_primitive_type(name) => {
+            // None: a project type that shares its name with a known type
+            *ty = resolve_known_name(name, file, resolver).unwrap_or_else(|| RustType::Custom(name.clone()));
+        }
+        RustType::CustomGeneric { name, args }
+            if known_types::is_known_type_name(name.rsplit("::").next().unwrap_or(name)) =>
+        {
+            match resolve_known_name(name, file, resolver) {
+                // Known types serialize the same whatever their arguments (`DateTime<Utc>`)
+                Some(known) => *ty = known,
+                // A generic project type that shares its name with a known type keeps its arguments
+                None => {
+                    for t in args {
+                        resolve_known_type(t, file, mappings, resolver);
+                    }
+                }
+            }
+        }
+        RustType::CustomGeneric { name, args }
+            if args.len() == 1 && known_types::is_channel_type(&qualify_type_path(name, file, resolver)) =>
+        {
+            // Tauri IPC channel; the message type is what the frontend receives
+            let mut inner = args.remove(0);
+            resolve_known_type(&mut inner, file, mappings, resolver);
+            *ty = RustType::Channel(Box::new(inner));
+        }
+        RustType::Vec(inner) | RustType::Option(inner) | RustType::Channel(inner) => {
+            resolve_known_type(inner, file, mappings, resolver)
+        }
+        RustType::Array { elem, .. } => resolve_known_type(elem, file, mappings, resolver),
+        RustType::Result { ok, err } => {
+            resolve_known_type(ok, file, mappings, resolver);
+            if let Some(err) = err {
+                resolve_known_type(err, file, mappings, resolver);
+            }
+        }
+        RustType::HashMap { key, value } => {
+            resolve_known_type(key, file, mappings, resolver);
+            resolve_known_type(value, file, mappings, resolver);
+        }
+        RustType::Tuple(types) | RustType::CustomGeneric { args: types, .. } => {
+            for t in types {
+                resolve_known_type(t, file, mappings, resolver);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Resolve a type written with a known type's name through the file's imports
+///
+/// Returns `None` when the name refers to a project type.
+fn resolve_known_name(name: &str, file: &Path, resolver: &ModuleResolver) -> Option<RustType> {
+    let path = qualify_type_path(name, file, resolver);
+    if path.first().is_some_and(|first| first == "crate") {
+        None
+    } else if let Some(known) = known_types::lookup_known_type(&path.join("::")) {
+        Some(RustType::Primitive(format!("{}::{}", known.krate, known.name)))
+    } else if path.len() > 1 {
+        // Same name as a known type, but from another crate
+        Some(RustType::Primitive(path.join("::")))
+    } else {
+        Some(RustType::Primitive(name.to_string()))
+    }
+}
+
+/// `[types]` mappings with module file imports rewritten relative to the generated file `file`
+///
+/// Package imports are kept as is.
+fn mappings_for_file(mappings: &BTreeMap<String, TypeMapping>, file: &Path) -> BTreeMap<String, TypeMapping> {
+    mappings
+        .iter()
+        .map(|(key, mapping)| {
+            let mapping = match (mapping, mapping.import_path()) {
+                (TypeMapping::Imported { ts, .. }, Some(module)) => {
+                    let module = normalize_path(module);
+                    let mut import = calculate_relative_import(&module, &normalize_path(file));
+                    // The relative import drops the extension, which ESM imports may need
+                    if let Some(extension) = module.extension() {
+                        import = format!("{}.{}", import, extension.to_string_lossy());
+                    }
+                    TypeMapping::Imported { ts: ts.clone(), import: Some(import) }
+                }
+                _ => mapping.clone(),
+            };
+            (key.clone(), mapping)
+        })
+        .collect()
+}
+
+/// Make a path absolute and resolve its `.` and `..` components
+fn normalize_path(path: &Path) -> PathBuf {
+    let absolute = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+    let mut normalized = PathBuf::new();
+    for component in absolute.components() {
+        match component {
+            std::path::Component::CurDir => {}
+            std::path::Component::ParentDir => {
+                normalized.pop();
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+/// Make a path absolute relative to `module`, handling `crate`, `self` and `super`
+fn absolute_module_path(module: &[String], path: &[String]) -> Vec<String> {
+    if path.first().is_some_and(|s| s == "crate") {
+        return path.to_vec();
+    }
+
+    let mut result = module.to_vec();
+    let mut rest = path;
+    while let Some((first, tail)) = rest.split_first() {
+        match first.as_str() {
+            "self" => {}
+            "super" if result.len() > 1 => {
+                result.pop();
+            }
+            "super" => {}
+            _ => break,
+        }
+        rest = tail;
+    }
+    result.extend(rest.iter().cloned());
+    result
+}
+
+/// Write a generated file unless it already has the same content
+///
+/// The new content goes to a temporary file next to the target and is renamed
+/// over it, so an interrupted run never leaves a truncated file behind.
+pub fn write_generated_file(file: &GeneratedFile) -> Result<WriteStatus> {
+    let status = match fs::read(&file.path) {
+        Ok(existing) if existing == file.content.as_bytes() => return Ok(WriteStatus::Unchanged),
+        Ok(_) => WriteStatus::Updated,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => WriteStatus::Created,
+        Err(e) => {
+            return Err(e).with_context(|| {
+                format!("Failed to read generated file: {}", file.path.display())
+            })
+        }
+    };
+
+    let parent = match file.path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    if !parent.exists() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let file_name = file.path.file_name().unwrap_or_default().to_string_lossy();
+    let temp_path = parent.join(format!(".{}.{}.tmp", file_name, std::process::id()));
+
+    let result = fs::write(&temp_path, &file.content)
+        .and_then(|_| fs::rename(&temp_path, &file.path));
+    if let Err(e) = result {
+        let _ = fs::remove_file(&temp_path);
+        return Err(e)
+            .with_context(|| format!("Failed to write generated file: {}", file.path.display()));
+    }
+
+    Ok(status)
+}
+
 /// Collect custom type names from a RustType (returns a Vec)
 fn collect_custom_types_from_rust_type(ty: &RustType) -> Vec<String> {
     let mut types = HashSet::new();
@@ -689,9 +1640,22 @@ fn collect_custom_types_recursive(ty: &RustType, types: &mut HashSet<String>) {
         RustType::Custom(name) => {
              types.insert(name.clone());
         }
+        RustType::CustomGeneric { name, args } => {
+            types.insert(name.clone());
+            for arg in args {
+                collect_custom_types_recursive(arg, types);
+            }
+        }
         RustType::Vec(inner) => collect_custom_types_recursive(inner, types),
+        RustType::Array { elem, .. } => collect_custom_types_recursive(elem, types),
         RustType::Option(inner) => collect_custom_types_recursive(inner, types),
-        RustType::Result(ok) => collect_custom_types_recursive(ok, types),
+        RustType::Channel(inner) => collect_custom_types_recursive(inner, types),
+        RustType::Result { ok, err } => {
+            collect_custom_types_recursive(ok, types);
+            if let Some(err) = err {
+                collect_custom_types_recursive(err, types);
+            }
+        }
         RustType::HashMap { key, value } => {
             collect_custom_types_recursive(key, types);
             collect_custom_types_recursive(value, types);
@@ -708,7 +1672,10 @@ fn collect_custom_types_recursive(ty: &RustType, types: &mut HashSet<String>) {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::{CommandArg, EnumRepresentation, EnumVariant, StructField, TauriCommand, VariantData};
+    use crate::models::{
+        CommandArg, EnumRepresentation, EnumVariant, FieldPresence, StructField, StructKind, TauriCommand,
+        VariantData,
+    };
 
     fn test_path() -> PathBuf {
         PathBuf::from("test.rs")
@@ -744,7 +1711,10 @@ mod tests {
 
     #[test]
     fn test_collect_custom_types_result() {
-        let ty = RustType::Result(Box::new(RustType::Custom("Response".to_string())));
+        let ty = RustType::Result {
+            ok: Box::new(RustType::Custom("Response".to_string())),
+            err: Some(Box::new(RustType::Primitive("String".to_string()))),
+        };
         let types = collect_custom_types_from_rust_type(&ty);
         assert_eq!(types, vec!["Response"]);
     }
@@ -779,6 +1749,16 @@ mod tests {
         assert_eq!(types, vec!["User"]);
     }
 
+    #[test]
+    fn test_collect_custom_types_generic() {
+        let ty = RustType::CustomGeneric {
+            name: "Page".to_string(),
+            args: vec![RustType::Vec(Box::new(RustType::Custom("User".to_string())))],
+        };
+        let result = collect_custom_types_from_rust_type(&ty);
+        assert_eq!(result, vec!["Page".to_string(), "User".to_string()]);
+    }
+
     #[test]
     fn test_collect_custom_types_no_duplicates() {
         let ty = RustType::Tuple(vec![
@@ -811,15 +1791,29 @@ mod tests {
                     generics: vec![],
                     fields: vec![],
                     source_file: PathBuf::from("src/types.rs"),
+                    transparent: false,
+                    kind: StructKind::Named,
+                    docs: Default::default(),
+                    event: None,
+                    ts_name: None,
+                    export: false,
                 },
                 RustStruct {
                     name: "Item".to_string(),
                     generics: vec![],
                     fields: vec![],
                     source_file: PathBuf::from("src/types.rs"),
+                    transparent: false,
+                    kind: StructKind::Named,
+                    docs: Default::default(),
+                    event: None,
+                    ts_name: None,
+                    export: false,
                 },
             ],
             enums: vec![],
+            type_aliases: vec![],
+            registered_commands: vec![],
         };
 
         let mut used_types = HashMap::new();
@@ -842,8 +1836,16 @@ mod tests {
                 generics: vec![],
                 fields: vec![],
                 source_file: test_path(),
+                transparent: false,
+                kind: StructKind::Named,
+                docs: Default::default(),
+                event: None,
+                ts_name: None,
+                export: false,
             }],
             enums: vec![],
+            type_aliases: vec![],
+            registered_commands: vec![],
         };
 
         let used_types = HashMap::new(); // Empty - no types used
@@ -865,15 +1867,29 @@ mod tests {
                     generics: vec![],
                     fields: vec![],
                     source_file: PathBuf::from("src/a.rs"),
+                    transparent: false,
+                    kind: StructKind::Named,
+                    docs: Default::default(),
+                    event: None,
+                    ts_name: None,
+                    export: false,
                 },
                 RustStruct {
                     name: "User".to_string(),
                     generics: vec![],
                     fields: vec![],
                     source_file: PathBuf::from("src/b.rs"),
+                    transparent: false,
+                    kind: StructKind::Named,
+                    docs: Default::default(),
+                    event: None,
+                    ts_name: None,
+                    export: false,
                 },
             ],
             enums: vec![],
+            type_aliases: vec![],
+            registered_commands: vec![],
         };
 
         let mut used_types = HashMap::new();
@@ -900,9 +1916,14 @@ mod tests {
                     variants: vec![EnumVariant { has_explicit_rename: false,
                         name: "Active".to_string(),
                         data: VariantData::Unit,
+                        docs: Default::default(),
                     }],
                     source_file: PathBuf::from("src/types.rs"),
                     representation: EnumRepresentation::default(),
+                    docs: Default::default(),
+                    event: None,
+                    ts_name: None,
+                    export: false,
                 },
                 RustEnum {
                     name: "UnusedEnum".to_string(),
@@ -910,8 +1931,14 @@ mod tests {
                     variants: vec![],
                     source_file: PathBuf::from("src/types.rs"),
                     representation: EnumRepresentation::default(),
+                    docs: Default::default(),
+                    event: None,
+                    ts_name: None,
+                    export: false,
                 },
             ],
+            type_aliases: vec![],
+            registered_commands: vec![],
         };
 
         let mut used_types = HashMap::new();
@@ -963,6 +1990,11 @@ mod tests {
                     return_type: Some(RustType::Custom("User".to_string())),
                     source_file: PathBuf::from("src/a.rs"),
                     rename_all: None,
+                    docs: Default::default(),
+                    plugin: None,
+                    raw_body: false,
+                    ts_name: None,
+                    ts_rename_all: None,
                 },
                 TauriCommand {
                     name: "get_user_b".to_string(),
@@ -970,6 +2002,11 @@ mod tests {
                     return_type: Some(RustType::Custom("User".to_string())),
                     source_file: PathBuf::from("src/b.rs"),
                     rename_all: None,
+                    docs: Default::default(),
+                    plugin: None,
+                    raw_body: false,
+                    ts_name: None,
+                    ts_rename_all: None,
                 },
             ],
             structs: vec![
@@ -979,8 +2016,17 @@ mod tests {
                     fields: vec![StructField { has_explicit_rename: false, use_optional: false,
                         name: "id".to_string(),
                         ty: RustType::Primitive("i32".to_string()),
+                        presence: FieldPresence::Always,
+                        flatten: false,
+                        docs: Default::default(),
                     }],
                     source_file: PathBuf::from("src/a.rs"),
+                    transparent: false,
+                    kind: StructKind::Named,
+                    docs: Default::default(),
+                    event: None,
+                    ts_name: None,
+                    export: false,
                 },
                 RustStruct {
                     name: "User".to_string(),
@@ -988,14 +2034,25 @@ mod tests {
                     fields: vec![StructField { has_explicit_rename: false, use_optional: false,
                         name: "name".to_string(),
                         ty: RustType::Primitive("String".to_string()),
+                        presence: FieldPresence::Always,
+                        flatten: false,
+                        docs: Default::default(),
                     }],
                     source_file: PathBuf::from("src/b.rs"),
+                    transparent: false,
+                    kind: StructKind::Named,
+                    docs: Default::default(),
+                    event: None,
+                    ts_name: None,
+                    export: false,
                 },
             ],
             enums: vec![],
+            type_aliases: vec![],
+            registered_commands: vec![],
         };
 
-        let result = pipeline.collect_used_types(&parse_result, &resolver);
+        let result = pipeline.collect_used_types(&parse_result, &resolver, false);
 
         // Should detect a conflict since User comes from two different files
         assert!(result.conflicts.contains_key("User"));
@@ -1024,22 +2081,87 @@ mod tests {
                 return_type: Some(RustType::Custom("User".to_string())),
                 source_file: PathBuf::from("src/types.rs"),
                 rename_all: None,
+                docs: Default::default(),
+                plugin: None,
+                raw_body: false,
+                ts_name: None,
+                ts_rename_all: None,
             }],
             structs: vec![RustStruct {
                 name: "User".to_string(),
                 generics: vec![],
                 fields: vec![],
                 source_file: PathBuf::from("src/types.rs"),
+                transparent: false,
+                kind: StructKind::Named,
+                docs: Default::default(),
+                event: None,
+                ts_name: None,
+                export: false,
             }],
             enums: vec![],
+            type_aliases: vec![],
+            registered_commands: vec![],
         };
 
-        let result = pipeline.collect_used_types(&parse_result, &resolver);
+        let result = pipeline.collect_used_types(&parse_result, &resolver, false);
 
         assert!(result.conflicts.is_empty());
         assert!(result.resolved.contains_key("User"));
     }
 
+    #[test]
+    fn test_collect_types_through_type_alias() {
+        let pipeline = Pipeline::new(false);
+        let mut resolver = ModuleResolver::new();
+
+        let path = PathBuf::from("src/types.rs");
+        let code = r#"
+            pub struct User { pub id: i32 }
+            pub type UserList = Vec<User>;
+            pub type Unused = Vec<i32>;
+        "#;
+        resolver.parse_file(&path, code, &PathBuf::from("src")).unwrap();
+
+        let parse_result = ParseResult {
+            commands: vec![TauriCommand {
+                name: "list_users".to_string(),
+                args: vec![],
+                return_type: Some(RustType::Custom("UserList".to_string())),
+                source_file: path.clone(),
+                rename_all: None,
+                docs: Default::default(),
+                plugin: None,
+                raw_body: false,
+                ts_name: None,
+                ts_rename_all: None,
+            }],
+            structs: vec![RustStruct {
+                name: "User".to_string(),
+                generics: vec![],
+                fields: vec![],
+                source_file: path.clone(),
+                transparent: false,
+                kind: StructKind::Named,
+                docs: Default::default(),
+                event: None,
+                ts_name: None,
+                export: false,
+            }],
+            enums: vec![],
+            type_aliases: crate::parser::parse_type_aliases(code, &path).unwrap(),
+            registered_commands: vec![],
+        };
+
+        let result = pipeline.collect_used_types(&parse_result, &resolver, false);
+        assert!(result.resolved.contains_key("UserList"));
+        assert!(result.resolved.contains_key("User"));
+
+        let aliases = pipeline.filter_type_aliases(&parse_result, &result);
+        assert_eq!(aliases.len(), 1);
+        assert_eq!(aliases[0].name, "UserList");
+    }
+
     #[test]
     fn test_pipeline_verbose_mode() {
         let pipeline = Pipeline::new(true);
@@ -1072,10 +2194,16 @@ mod tests {
                 args: vec![CommandArg {
                     name: "req".to_string(),
                     ty: RustType::Custom("Request".to_string()),
+                    docs: Default::default(),
                 }],
                 return_type: Some(RustType::Custom("Response".to_string())),
                 source_file: PathBuf::from("src/types.rs"),
                 rename_all: None,
+                docs: Default::default(),
+                plugin: None,
+                raw_body: false,
+                ts_name: None,
+                ts_rename_all: None,
             }],
             structs: vec![
                 RustStruct {
@@ -1083,18 +2211,32 @@ mod tests {
                     generics: vec![],
                     fields: vec![],
                     source_file: PathBuf::from("src/types.rs"),
+                    transparent: false,
+                    kind: StructKind::Named,
+                    docs: Default::default(),
+                    event: None,
+                    ts_name: None,
+                    export: false,
                 },
                 RustStruct {
                     name: "Response".to_string(),
                     generics: vec![],
                     fields: vec![],
                     source_file: PathBuf::from("src/types.rs"),
+                    transparent: false,
+                    kind: StructKind::Named,
+                    docs: Default::default(),
+                    event: None,
+                    ts_name: None,
+                    export: false,
                 },
             ],
             enums: vec![],
+            type_aliases: vec![],
+            registered_commands: vec![],
         };
 
-        let result = pipeline.collect_used_types(&parse_result, &resolver);
+        let result = pipeline.collect_used_types(&parse_result, &resolver, false);
 
         assert!(result.resolved.contains_key("Request"));
         assert!(result.resolved.contains_key("Response"));
@@ -1112,28 +2254,74 @@ mod tests {
                 CommandArg {
                     name: "state".to_string(),
                     ty: RustType::Custom("State".to_string()),
+                    docs: Default::default(),
                 },
                 CommandArg {
                     name: "window".to_string(),
                     ty: RustType::Custom("Window".to_string()),
+                    docs: Default::default(),
                 },
                 CommandArg {
                     name: "id".to_string(),
                     ty: RustType::Primitive("i32".to_string()),
+                    docs: Default::default(),
                 },
             ],
             return_type: None,
             source_file: test_path(),
             rename_all: None,
+            docs: Default::default(),
+            plugin: None,
+            raw_body: false,
+            ts_name: None,
+            ts_rename_all: None,
         }];
 
-        pipeline.filter_tauri_special_args(&mut commands, &resolver);
+        pipeline.filter_tauri_special_args(&mut commands, &[], &resolver);
 
         // State and Window should be filtered out
         assert_eq!(commands[0].args.len(), 1);
         assert_eq!(commands[0].args[0].name, "id");
     }
 
+    #[test]
+    fn test_filter_tauri_special_types_with_generics() {
+        let pipeline = Pipeline::new(false);
+        let resolver = ModuleResolver::new();
+
+        let mut commands = vec![TauriCommand {
+            name: "with_state".to_string(),
+            args: vec![
+                CommandArg {
+                    name: "state".to_string(),
+                    ty: RustType::CustomGeneric {
+                        name: "tauri::State".to_string(),
+                        args: vec![RustType::Custom("AppState".to_string())],
+                    },
+                    docs: Default::default(),
+                },
+                CommandArg {
+                    name: "id".to_string(),
+                    ty: RustType::Primitive("i32".to_string()),
+                    docs: Default::default(),
+                },
+            ],
+            return_type: None,
+            source_file: test_path(),
+            rename_all: None,
+            docs: Default::default(),
+            plugin: None,
+            raw_body: false,
+            ts_name: None,
+            ts_rename_all: None,
+        }];
+
+        pipeline.filter_tauri_special_args(&mut commands, &[], &resolver);
+
+        assert_eq!(commands[0].args.len(), 1);
+        assert_eq!(commands[0].args[0].name, "id");
+    }
+
     #[test]
     fn test_filter_tauri_app_handle() {
         let pipeline = Pipeline::new(false);
@@ -1145,18 +2333,25 @@ mod tests {
                 CommandArg {
                     name: "app".to_string(),
                     ty: RustType::Custom("AppHandle".to_string()),
+                    docs: Default::default(),
                 },
                 CommandArg {
                     name: "data".to_string(),
                     ty: RustType::Primitive("String".to_string()),
+                    docs: Default::default(),
                 },
             ],
             return_type: None,
             source_file: test_path(),
             rename_all: None,
+            docs: Default::default(),
+            plugin: None,
+            raw_body: false,
+            ts_name: None,
+            ts_rename_all: None,
         }];
 
-        pipeline.filter_tauri_special_args(&mut commands, &resolver);
+        pipeline.filter_tauri_special_args(&mut commands, &[], &resolver);
 
         // AppHandle should be filtered out
         assert_eq!(commands[0].args.len(), 1);
@@ -1179,22 +2374,109 @@ mod tests {
                 CommandArg {
                     name: "state".to_string(),
                     ty: RustType::Custom("MyState".to_string()),
+                    docs: Default::default(),
                 },
                 CommandArg {
                     name: "id".to_string(),
                     ty: RustType::Primitive("i32".to_string()),
+                    docs: Default::default(),
                 },
             ],
             return_type: None,
             source_file: path.clone(),
             rename_all: None,
+            docs: Default::default(),
+            plugin: None,
+            raw_body: false,
+            ts_name: None,
+            ts_rename_all: None,
         }];
 
-        pipeline.filter_tauri_special_args(&mut commands, &resolver);
+        pipeline.filter_tauri_special_args(&mut commands, &[], &resolver);
 
         // MyState (alias to State) should be filtered out
         assert_eq!(commands[0].args.len(), 1);
         assert_eq!(commands[0].args[0].name, "id");
     }
-}
 
+    #[test]
+    fn test_filter_injected_types_by_qualified_path() {
+        let pipeline = Pipeline::new(false);
+        let mut resolver = ModuleResolver::new();
+        let base = PathBuf::from("src");
+
+        let commands_file = PathBuf::from("src/commands.rs");
+        let code = r#"
+            use tauri::ipc::Request;
+            use crate::db::DbConn;
+            use crate::api::Request as ApiRequest;
+        "#;
+        resolver.parse_file(&commands_file, code, &base).unwrap();
+        resolver
+            .parse_file(&PathBuf::from("src/db.rs"), "pub struct DbConn;", &base)
+            .unwrap();
+        resolver
+            .parse_file(&PathBuf::from("src/api.rs"), "pub struct Request;", &base)
+            .unwrap();
+
+        let arg = |name: &str, ty: &str| CommandArg {
+            name: name.to_string(),
+            ty: RustType::Custom(ty.to_string()),
+            docs: Default::default(),
+        };
+        let mut commands = vec![TauriCommand {
+            name: "upload".to_string(),
+            args: vec![
+                arg("request", "Request"),
+                arg("db", "DbConn"),
+                arg("scope", "tauri::ipc::CommandScope"),
+                arg("body", "ApiRequest"),
+            ],
+            return_type: None,
+            source_file: commands_file,
+            rename_all: None,
+            docs: Default::default(),
+            plugin: None,
+            raw_body: false,
+            ts_name: None,
+            ts_rename_all: None,
+        }];
+
+        let injected = vec!["crate::db::DbConn".to_string()];
+        pipeline.filter_tauri_special_args(&mut commands, &injected, &resolver);
+
+        let names: Vec<_> = commands[0].args.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(names, vec!["body"]);
+    }
+
+    #[test]
+    fn test_write_generated_file_statuses() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut file = GeneratedFile {
+            path: dir.path().join("out").join("types.ts"),
+            content: "export type A = string;\n".to_string(),
+        };
+
+        assert_eq!(write_generated_file(&file).unwrap(), WriteStatus::Created);
+        assert_eq!(write_generated_file(&file).unwrap(), WriteStatus::Unchanged);
+
+        file.content = "export type A = number;\n".to_string();
+        assert_eq!(write_generated_file(&file).unwrap(), WriteStatus::Updated);
+        assert_eq!(fs::read_to_string(&file.path).unwrap(), file.content);
+
+        // No temporary files are left behind
+        let entries: Vec<_> = fs::read_dir(dir.path().join("out")).unwrap().collect();
+        assert_eq!(entries.len(), 1);
+    }
+
+    #[test]
+    fn test_absolute_module_path() {
+        let module = vec!["crate".to_string(), "commands".to_string()];
+        let path = |s: &str| s.split("::").map(String::from).collect::<Vec<_>>();
+
+        assert_eq!(absolute_module_path(&module, &path("crate::a::f")), path("crate::a::f"));
+        assert_eq!(absolute_module_path(&module, &path("self::user::f")), path("crate::commands::user::f"));
+        assert_eq!(absolute_module_path(&module, &path("super::f")), path("crate::f"));
+        assert_eq!(absolute_module_path(&module, &path("user::f")), path("crate::commands::user::f"));
+    }
+}
@@ -39,6 +39,11 @@ impl Scanner {
         Ok(rust_files)
     }
 
+    /// Check if a file would be picked up by `scan`
+    pub fn is_scanned_file(&self, path: &Path) -> bool {
+        self.is_rust_file(path) && !self.is_excluded(path)
+    }
+
     /// Check if a path is a Rust source file
     fn is_rust_file(&self, path: &Path) -> bool {
         path.extension()
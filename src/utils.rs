@@ -22,6 +22,16 @@ pub fn to_camel_case(s: &str) -> String {
     result
 }
 
+/// Convert snake_case to PascalCase
+pub fn to_pascal_case(s: &str) -> String {
+    let camel = to_camel_case(s);
+    let mut chars = camel.chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
 /// Convert PascalCase to snake_case
 pub fn to_snake_case(s: &str) -> String {
     let mut result = String::new();
@@ -49,6 +59,38 @@ pub fn to_screaming_kebab_case(s: &str) -> String {
     to_kebab_case(s).to_uppercase()
 }
 
+/// Apply rename_all transformation to a field name (fields are snake_case in Rust)
+pub fn apply_field_rename_all(name: &str, rename_all: &Option<String>) -> Option<String> {
+    let rule = rename_all.as_ref()?;
+    Some(match rule.as_str() {
+        "lowercase" => name.to_lowercase(),
+        "UPPERCASE" => name.to_uppercase(),
+        "camelCase" => to_camel_case(name),
+        "PascalCase" => to_pascal_case(name),
+        "snake_case" => name.to_string(),
+        "SCREAMING_SNAKE_CASE" => name.to_uppercase(),
+        "kebab-case" => name.replace('_', "-"),
+        "SCREAMING-KEBAB-CASE" => name.replace('_', "-").to_uppercase(),
+        _ => name.to_string(),
+    })
+}
+
+/// Render a name as a TypeScript property key, quoting it unless it's a valid identifier
+///
+/// Serde renames like `kebab-case` or `"x-api-key"` produce keys that must be written as `"content-type": string`.
+pub fn property_name(name: &str) -> String {
+    let mut chars = name.chars();
+    let is_identifier = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    if is_identifier {
+        name.to_string()
+    } else {
+        format!("{:?}", name)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -75,10 +117,27 @@ mod tests {
         assert_eq!(to_camel_case("get_user_1"), "getUser1");
     }
 
+    #[test]
+    fn test_to_pascal_case() {
+        assert_eq!(to_pascal_case("user_name"), "UserName");
+        assert_eq!(to_pascal_case("id"), "Id");
+        assert_eq!(to_pascal_case(""), "");
+    }
+
     #[test]
     fn test_to_camel_case_already_camel() {
         assert_eq!(to_camel_case("getUser"), "getUser");
         assert_eq!(to_camel_case("getUserById"), "getUserById");
     }
-}
 
+    #[test]
+    fn test_property_name() {
+        assert_eq!(property_name("userId"), "userId");
+        assert_eq!(property_name("_private"), "_private");
+        assert_eq!(property_name("$ref"), "$ref");
+        assert_eq!(property_name("content-type"), "\"content-type\"");
+        assert_eq!(property_name("2fa"), "\"2fa\"");
+        assert_eq!(property_name("a b"), "\"a b\"");
+        assert_eq!(property_name(""), "\"\"");
+    }
+}
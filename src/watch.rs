@@ -0,0 +1,285 @@
+//! Watch mode - regenerate bindings whenever Rust sources or the config change
+
+use anyhow::{Context, Result};
+use notify::event::ModifyKind;
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::time::Duration;
+
+use crate::config::Config;
+use crate::pipeline::Pipeline;
+use crate::scanner::Scanner;
+
+/// Quiet period after the last change before regenerating, so a burst of saves runs once
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// What a file system event means for the generator
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Change {
+    Irrelevant,
+    Source,
+    Config,
+}
+
+/// Paths the watcher compares events against
+struct WatchPaths {
+    /// Canonical path of the config file
+    config_file: PathBuf,
+    /// Canonical path of `input.source_dir`
+    source_dir: PathBuf,
+}
+
+impl WatchPaths {
+    fn new(config_path: &Path, config: &Config) -> Result<Self> {
+        let config_dir = config_path
+            .parent()
+            .filter(|p| !p.as_os_str().is_empty())
+            .unwrap_or(Path::new("."));
+        let config_dir = fs::canonicalize(config_dir).with_context(|| {
+            format!("Failed to resolve config directory: {}", config_dir.display())
+        })?;
+        let config_file = config_dir.join(config_path.file_name().unwrap_or_default());
+
+        let source_dir = fs::canonicalize(&config.input.source_dir).with_context(|| {
+            format!(
+                "Failed to resolve source directory: {}",
+                config.input.source_dir.display()
+            )
+        })?;
+
+        Ok(Self {
+            config_file,
+            source_dir,
+        })
+    }
+
+    /// Classify an event, applying the same exclusions as the scanner
+    fn classify(&self, event: &Event, config: &Config) -> Change {
+        let is_write = match event.kind {
+            EventKind::Create(_) | EventKind::Remove(_) => true,
+            EventKind::Modify(ModifyKind::Metadata(_)) => false,
+            EventKind::Modify(_) => true,
+            _ => false,
+        };
+        if !is_write {
+            return Change::Irrelevant;
+        }
+
+        let scanner = Scanner::new(config.input.source_dir.clone(), config.input.exclude.clone());
+
+        event
+            .paths
+            .iter()
+            .map(|path| {
+                if *path == self.config_file {
+                    return Change::Config;
+                }
+                match path.strip_prefix(&self.source_dir) {
+                    // Check the path as the scanner would see it, relative to source_dir
+                    Ok(relative) if scanner.is_scanned_file(&config.input.source_dir.join(relative)) => {
+                        Change::Source
+                    }
+                    _ => Change::Irrelevant,
+                }
+            })
+            .max()
+            .unwrap_or(Change::Irrelevant)
+    }
+}
+
+/// Generate once, then regenerate on every relevant change until interrupted
+pub fn watch(config_path: &Path, verbose: bool) -> Result<()> {
+    let mut config = Config::load(config_path)?;
+    let mut paths = WatchPaths::new(config_path, &config)?;
+    // A file that doesn't parse mid-edit would otherwise publish partial bindings
+    let pipeline = Pipeline::new(verbose).fail_on_parse_errors(true);
+
+    let (tx, rx) = mpsc::channel::<notify::Result<Event>>();
+    let mut watcher = notify::recommended_watcher(tx).context("Failed to start file watcher")?;
+
+    // Editors often save by replacing the file, so watch the config's directory instead
+    if let Some(config_dir) = paths.config_file.parent() {
+        watcher
+            .watch(config_dir, RecursiveMode::NonRecursive)
+            .with_context(|| format!("Failed to watch {}", config_dir.display()))?;
+    }
+    watch_source_dir(&mut watcher, &paths.source_dir)?;
+
+    regenerate(&pipeline, &config);
+    println!(
+        "Watching {} for changes (press Ctrl+C to stop)",
+        config.input.source_dir.display()
+    );
+
+    while let Ok(first) = rx.recv() {
+        let mut change = Change::Irrelevant;
+        let mut record = |event: notify::Result<Event>| match event {
+            Ok(event) => change = change.max(paths.classify(&event, &config)),
+            Err(e) => eprintln!("Warning: file watcher error: {}", e),
+        };
+
+        record(first);
+        while let Ok(event) = rx.recv_timeout(DEBOUNCE) {
+            record(event);
+        }
+
+        match change {
+            Change::Irrelevant => continue,
+            Change::Source => {}
+            Change::Config => match Config::load(config_path) {
+                Ok(new_config) => {
+                    println!("Configuration changed, reloading");
+                    if new_config.input.source_dir != config.input.source_dir {
+                        match WatchPaths::new(config_path, &new_config) {
+                            Ok(new_paths) => {
+                                let _ = watcher.unwatch(&paths.source_dir);
+                                watch_source_dir(&mut watcher, &new_paths.source_dir)?;
+                                paths = new_paths;
+                            }
+                            Err(e) => {
+                                eprintln!("Error: {:#}", e);
+                                continue;
+                            }
+                        }
+                    }
+                    config = new_config;
+                }
+                Err(e) => {
+                    // Keep the previous configuration until the file is fixed
+                    eprintln!("Error: {:#}", e);
+                    continue;
+                }
+            },
+        }
+
+        regenerate(&pipeline, &config);
+    }
+
+    Ok(())
+}
+
+fn watch_source_dir(watcher: &mut RecommendedWatcher, source_dir: &Path) -> Result<()> {
+    watcher
+        .watch(source_dir, RecursiveMode::Recursive)
+        .with_context(|| format!("Failed to watch {}", source_dir.display()))
+}
+
+/// Run the pipeline, reporting errors instead of exiting
+fn regenerate(pipeline: &Pipeline, config: &Config) {
+    if let Err(e) = pipeline.run(config) {
+        eprintln!("Error: {:#}", e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{InputConfig, NamingConfig, OutputConfig};
+    use notify::event::{AccessKind, CreateKind, DataChange, MetadataKind};
+    use tempfile::tempdir;
+
+    fn setup() -> (tempfile::TempDir, PathBuf, Config, WatchPaths) {
+        let dir = tempdir().unwrap();
+        let src = dir.path().join("src");
+        fs::create_dir_all(&src).unwrap();
+        let config_path = dir.path().join("tauri-codegen.toml");
+
+        let config = Config {
+            input: InputConfig {
+                source_dir: src,
+                exclude: vec!["target".to_string()],
+                use_cargo_expand: false,
+                cargo_manifest: None,
+                only_registered_commands: false,
+                plugins: Default::default(),
+                injected_types: Vec::new(),
+            },
+            output: OutputConfig {
+                types_file: dir.path().join("types.ts"),
+                commands_file: dir.path().join("commands.ts"),
+                events_file: None,
+                max_array_tuple_len: 16,
+                error_handling: Default::default(),
+                export_all_serializable: false,
+            },
+            naming: NamingConfig::default(),
+            types: Default::default(),
+        };
+        let paths = WatchPaths::new(&config_path, &config).unwrap();
+        let root = fs::canonicalize(dir.path()).unwrap();
+        (dir, root, config, paths)
+    }
+
+    fn modified(path: PathBuf) -> Event {
+        Event::new(EventKind::Modify(ModifyKind::Data(DataChange::Any))).add_path(path)
+    }
+
+    #[test]
+    fn test_classify_source_changes() {
+        let (_dir, root, config, paths) = setup();
+
+        assert_eq!(
+            paths.classify(&modified(root.join("src/lib.rs")), &config),
+            Change::Source
+        );
+        let created = Event::new(EventKind::Create(CreateKind::File)).add_path(root.join("src/api/mod.rs"));
+        assert_eq!(paths.classify(&created, &config), Change::Source);
+    }
+
+    #[test]
+    fn test_classify_ignores_excluded_and_non_rust_files() {
+        let (_dir, root, config, paths) = setup();
+
+        assert_eq!(
+            paths.classify(&modified(root.join("src/target/gen.rs")), &config),
+            Change::Irrelevant
+        );
+        assert_eq!(
+            paths.classify(&modified(root.join("src/notes.md")), &config),
+            Change::Irrelevant
+        );
+        assert_eq!(
+            paths.classify(&modified(root.join("types.ts")), &config),
+            Change::Irrelevant
+        );
+    }
+
+    #[test]
+    fn test_classify_ignores_reads_and_metadata() {
+        let (_dir, root, config, paths) = setup();
+
+        let read = Event::new(EventKind::Access(AccessKind::Any)).add_path(root.join("src/lib.rs"));
+        assert_eq!(paths.classify(&read, &config), Change::Irrelevant);
+
+        let touched = Event::new(EventKind::Modify(ModifyKind::Metadata(MetadataKind::Any)))
+            .add_path(root.join("src/lib.rs"));
+        assert_eq!(paths.classify(&touched, &config), Change::Irrelevant);
+    }
+
+    #[test]
+    fn test_regenerate_keeps_output_when_a_file_does_not_parse() {
+        let (_dir, _root, config, _paths) = setup();
+        let pipeline = Pipeline::new(false).fail_on_parse_errors(true);
+
+        let lib = config.input.source_dir.join("lib.rs");
+        fs::write(&lib, "#[tauri::command]\npub fn ping() {}\n").unwrap();
+        regenerate(&pipeline, &config);
+        let generated = fs::read_to_string(&config.output.commands_file).unwrap();
+        assert!(generated.contains("export async function ping()"));
+
+        // A half-written edit removes nothing from the bindings on disk
+        fs::write(&lib, "#[tauri::command]\npub fn ping() {}\n\npub fn broken( {\n").unwrap();
+        regenerate(&pipeline, &config);
+        assert_eq!(fs::read_to_string(&config.output.commands_file).unwrap(), generated);
+    }
+
+    #[test]
+    fn test_classify_config_change() {
+        let (_dir, root, config, paths) = setup();
+
+        let event = modified(root.join("tauri-codegen.toml")).add_path(root.join("src/lib.rs"));
+        assert_eq!(paths.classify(&event, &config), Change::Config);
+    }
+}
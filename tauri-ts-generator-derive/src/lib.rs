@@ -1,20 +1,27 @@
 //! Derive macro for tauri-ts-generator
 //!
 //! This crate provides the `#[derive(TS)]` macro which registers the `ts` attribute
-//! namespace, allowing `#[ts(optional)]` annotations on struct fields.
+//! namespace, allowing `#[ts(optional)]` annotations on struct fields,
+//! `#[ts(event = "...")]` on event payload types, `#[ts(type = "...")]` /
+//! `#[ts(as = "...")]` overrides on types and fields, `#[ts(export)]` on types,
+//! and `skip`/`rename`/`rename_all`.
+//! The `ts` attribute macro accepts the naming options on commands.
 //!
 //! The derive macro itself is a no-op - it doesn't generate any runtime code.
 //! Its sole purpose is to make the Rust compiler accept `#[ts(...)]` attributes
-//! which are then parsed at code generation time by `tauri-ts-generator`.
+//! (and reject malformed ones) which are then parsed at code generation time by
+//! `tauri-ts-generator`.
 
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{parse_macro_input, DeriveInput};
+use syn::meta::ParseNestedMeta;
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Fields, ItemFn, LitStr};
 
 /// Derive macro that enables `#[ts(...)]` attributes on struct/enum fields.
 ///
 /// This macro is a no-op at compile time. It simply registers the `ts` attribute
-/// namespace so that the Rust compiler doesn't error on `#[ts(optional)]` attributes.
+/// namespace so that the Rust compiler doesn't error on `#[ts(optional)]` attributes,
+/// and reports unknown or malformed `#[ts(...)]` options.
 ///
 /// # Example
 ///
@@ -29,13 +36,274 @@ use syn::{parse_macro_input, DeriveInput};
 ///     
 ///     // This field will be typed as `string | null` (default behavior)
 ///     pub name: Option<String>,
+///
+///     // Serialized with a custom serde helper, so spell out the wire type
+///     #[ts(type = "string")]
+///     pub key: Vec<u8>,
 /// }
 /// ```
 #[proc_macro_derive(TS, attributes(ts))]
 pub fn derive_ts(input: TokenStream) -> TokenStream {
-    // Parse the input to validate syntax, but don't generate any code
-    let _ = parse_macro_input!(input as DeriveInput);
-    
-    // Return empty token stream - this is a no-op derive
-    TokenStream::from(quote! {})
+    let input = parse_macro_input!(input as DeriveInput);
+
+    // No code is generated, only the #[ts(...)] options are checked
+    match validate(&input) {
+        Ok(()) => TokenStream::from(quote! {}),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Attribute macro for `#[ts(...)]` options on commands: `skip`, `rename` and `rename_all`
+///
+/// Functions can't use the derive's helper attribute, so write it with its path:
+/// a bare `#[ts]` would be ambiguous with the helper. Like the derive, it leaves
+/// the function unchanged and only checks the options.
+///
+/// # Example
+///
+/// ```rust
+/// // Generated as `fetchUser(user_id: number)` instead of `getUser(userId: number)`
+/// #[tauri_ts_generator_derive::ts(rename = "fetchUser", rename_all = "snake_case")]
+/// fn get_user(user_id: u32) {}
+/// ```
+#[proc_macro_attribute]
+pub fn ts(args: TokenStream, item: TokenStream) -> TokenStream {
+    let mut has_override = false;
+    let options = syn::meta::parser(|meta| check_option(meta, Position::Command, &mut has_override));
+    parse_macro_input!(args with options);
+
+    let function = parse_macro_input!(item as ItemFn);
+    TokenStream::from(quote! { #function })
+}
+
+/// Where a `#[ts(...)]` attribute is placed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Position {
+    /// On the struct or enum itself
+    Container,
+    /// On an enum variant
+    Variant,
+    /// On a struct or variant field
+    Field,
+    /// On a command function, through the `ts` attribute macro
+    Command,
+}
+
+/// Check the `#[ts(...)]` attributes on the type, its variants and fields
+fn validate(input: &DeriveInput) -> syn::Result<()> {
+    let mut errors = Vec::new();
+
+    check_attrs(&input.attrs, Position::Container, &mut errors);
+    match &input.data {
+        Data::Struct(data) => check_fields(&data.fields, &mut errors),
+        Data::Enum(data) => {
+            for variant in &data.variants {
+                check_attrs(&variant.attrs, Position::Variant, &mut errors);
+                check_fields(&variant.fields, &mut errors);
+            }
+        }
+        Data::Union(data) => {
+            for field in &data.fields.named {
+                check_attrs(&field.attrs, Position::Field, &mut errors);
+            }
+        }
+    }
+
+    // Report every problem at once
+    let mut errors = errors.into_iter();
+    match errors.next() {
+        None => Ok(()),
+        Some(mut first) => {
+            first.extend(errors);
+            Err(first)
+        }
+    }
+}
+
+fn check_fields(fields: &Fields, errors: &mut Vec<syn::Error>) {
+    for field in fields {
+        check_attrs(&field.attrs, Position::Field, errors);
+    }
+}
+
+fn check_attrs(attrs: &[Attribute], position: Position, errors: &mut Vec<syn::Error>) {
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("ts")) {
+        let mut has_override = false;
+        if let Err(err) = attr.parse_nested_meta(|meta| check_option(meta, position, &mut has_override)) {
+            errors.push(err);
+        }
+    }
+}
+
+/// Casing rules accepted by `rename_all`, the same as serde's
+const RENAME_RULES: &[&str] = &[
+    "lowercase",
+    "UPPERCASE",
+    "PascalCase",
+    "camelCase",
+    "snake_case",
+    "SCREAMING_SNAKE_CASE",
+    "kebab-case",
+    "SCREAMING-KEBAB-CASE",
+];
+
+/// Check one option of a `#[ts(...)]` attribute
+fn check_option(meta: ParseNestedMeta, position: Position, has_override: &mut bool) -> syn::Result<()> {
+    let name = meta.path.get_ident().map(|ident| ident.to_string()).unwrap_or_default();
+    match (name.as_str(), position) {
+        ("optional", Position::Field) | ("export", Position::Container) | ("skip", _) => Ok(()),
+        ("event", Position::Container) | ("rename", _) => {
+            meta.value()?.parse::<LitStr>()?;
+            Ok(())
+        }
+        ("rename_all", Position::Container | Position::Variant | Position::Command) => {
+            let lit: LitStr = meta.value()?.parse()?;
+            if !RENAME_RULES.contains(&lit.value().as_str()) {
+                return Err(syn::Error::new(
+                    lit.span(),
+                    format!("unknown rename_all rule, expected one of: {}", RENAME_RULES.join(", ")),
+                ));
+            }
+            Ok(())
+        }
+        ("type" | "as", Position::Container | Position::Field) => {
+            if *has_override {
+                return Err(meta.error("only one of `type` and `as` can be given"));
+            }
+            *has_override = true;
+            let lit: LitStr = meta.value()?.parse()?;
+            if name == "as" {
+                // `as` names a Rust type, `type` is TypeScript and taken verbatim
+                lit.parse::<syn::Type>()?;
+            }
+            Ok(())
+        }
+        _ => Err(meta.error(format!(
+            "unsupported #[ts] option `{}` on a {}",
+            name,
+            position_name(position)
+        ))),
+    }
+}
+
+fn position_name(position: Position) -> &'static str {
+    match position {
+        Position::Container => "type",
+        Position::Variant => "variant",
+        Position::Field => "field",
+        Position::Command => "command",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    fn error(input: DeriveInput) -> String {
+        validate(&input).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn test_valid_attributes() {
+        let input: DeriveInput = parse_quote! {
+            #[ts(event = "changed", as = "Vec<String>", export)]
+            struct Changed {
+                #[ts(optional)]
+                a: Option<u8>,
+                #[ts(type = "`0x${string}`")]
+                b: Vec<u8>,
+                #[ts(as = "std::collections::HashMap<String, u32>")]
+                c: Counts,
+            }
+        };
+        assert!(validate(&input).is_ok());
+
+        let input: DeriveInput = parse_quote! {
+            enum Message {
+                Text(#[ts(type = "string")] Bytes),
+                Ping { #[ts(optional)] at: Option<u64> },
+            }
+        };
+        assert!(validate(&input).is_ok());
+    }
+
+    #[test]
+    fn test_unknown_option() {
+        let input: DeriveInput = parse_quote! {
+            struct User {
+                #[ts(optionl)]
+                name: Option<String>,
+            }
+        };
+        assert_eq!(error(input), "unsupported #[ts] option `optionl` on a field");
+    }
+
+    #[test]
+    fn test_option_in_wrong_position() {
+        let input: DeriveInput = parse_quote! {
+            enum Status {
+                #[ts(type = "string")]
+                Active,
+            }
+        };
+        assert_eq!(error(input), "unsupported #[ts] option `type` on a variant");
+    }
+
+    #[test]
+    fn test_naming_options() {
+        let input: DeriveInput = parse_quote! {
+            #[ts(rename = "UserDto", rename_all = "snake_case")]
+            enum User {
+                #[ts(rename = "guest", rename_all = "PascalCase")]
+                Guest { #[ts(rename = "id")] user_id: u32, #[ts(skip)] token: String },
+                #[ts(skip)]
+                Internal,
+            }
+        };
+        assert!(validate(&input).is_ok());
+
+        let input: DeriveInput = parse_quote! {
+            struct User {
+                #[ts(rename_all = "camelCase")]
+                name: String,
+            }
+        };
+        assert_eq!(error(input), "unsupported #[ts] option `rename_all` on a field");
+
+        let input: DeriveInput = parse_quote! {
+            #[ts(rename_all = "camel")]
+            struct User {
+                name: String,
+            }
+        };
+        assert!(error(input).starts_with("unknown rename_all rule"));
+    }
+
+    #[test]
+    fn test_malformed_values() {
+        let input: DeriveInput = parse_quote! {
+            struct User {
+                #[ts(as = "Vec<")]
+                ids: Ids,
+            }
+        };
+        assert!(validate(&input).is_err());
+
+        let input: DeriveInput = parse_quote! {
+            struct User {
+                #[ts(type = string)]
+                id: Id,
+            }
+        };
+        assert!(validate(&input).is_err());
+
+        let input: DeriveInput = parse_quote! {
+            struct User {
+                #[ts(type = "string", as = "String")]
+                id: Id,
+            }
+        };
+        assert_eq!(error(input), "only one of `type` and `as` can be given");
+    }
 }
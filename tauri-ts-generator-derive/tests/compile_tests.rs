@@ -77,6 +77,85 @@ struct ComplexTypes {
     vec_option: Option<Vec<i32>>,
 }
 
+/// Test: Container-level #[ts(event = "...")] compiles on structs and enums
+#[derive(TS)]
+#[ts(event = "download-progress")]
+struct DownloadProgress {
+    percent: f32,
+}
+
+#[derive(TS)]
+#[ts(event = "session-changed")]
+enum SessionChanged {
+    LoggedIn,
+    LoggedOut,
+}
+
+/// Test: #[ts(type = "...")] and #[ts(as = "...")] compile on types and fields
+#[derive(TS)]
+#[ts(as = "String")]
+struct Version {
+    major: u32,
+    minor: u32,
+}
+
+#[derive(TS)]
+struct Account {
+    #[ts(type = "`0x${string}`")]
+    key: Vec<u8>,
+    #[ts(as = "String")]
+    balance: u128,
+}
+
+#[derive(TS)]
+#[ts(type = "'low' | 'high'")]
+enum Priority {
+    Low,
+    High,
+    Custom(#[ts(as = "Option<String>")] u8),
+}
+
+/// Test: #[ts(skip)], #[ts(rename)] and #[ts(rename_all)] compile on types, variants and fields
+#[derive(TS)]
+#[ts(rename = "UserDto", rename_all = "snake_case")]
+struct User {
+    #[ts(rename = "type")]
+    kind: String,
+    #[ts(skip)]
+    password_hash: String,
+}
+
+#[derive(TS)]
+#[ts(rename_all = "kebab-case")]
+enum Role {
+    #[ts(rename = "admin", rename_all = "camelCase")]
+    Admin { granted_by: String },
+    #[ts(skip)]
+    System,
+}
+
+/// Test: #[ts(export)] compiles on types
+#[derive(TS)]
+#[ts(export)]
+struct Theme {
+    dark: bool,
+}
+
+/// Test: the ts attribute macro compiles on functions and methods next to the derive helper
+#[tauri_ts_generator_derive::ts(rename = "fetchUser", rename_all = "snake_case")]
+fn get_user(user_id: u32) -> u32 {
+    user_id
+}
+
+struct Api;
+
+impl Api {
+    #[tauri_ts_generator_derive::ts(skip)]
+    fn internal(&self) -> bool {
+        true
+    }
+}
+
 #[test]
 fn test_derive_compiles() {
     // This test just needs to compile - if it compiles, the derive macro works
@@ -90,6 +169,16 @@ fn test_derive_compiles() {
     let _ = WithOtherDerives { value: None };
     let _ = TupleStruct("test".to_string(), 42);
     let _ = ComplexTypes { nested_option: None, vec_option: Some(vec![1, 2, 3]) };
+    let _ = DownloadProgress { percent: 0.5 };
+    let _ = SessionChanged::LoggedIn;
+    let _ = Version { major: 1, minor: 0 };
+    let _ = Account { key: vec![0xab], balance: 10 };
+    let _ = Priority::Custom(1);
+    let _ = User { kind: "admin".to_string(), password_hash: String::new() };
+    let _ = Role::Admin { granted_by: "root".to_string() };
+    let _ = Theme { dark: true };
+    assert_eq!(get_user(7), 7);
+    assert!(Api.internal());
 }
 
 #[test]
@@ -6,8 +6,8 @@ use tauri_ts_generator::generator::{
     commands_gen::generate_commands_file, types_gen::generate_types_file, GeneratorContext,
 };
 use tauri_ts_generator::models::{
-    CommandArg, EnumRepresentation, EnumVariant, RustEnum, RustStruct, RustType, StructField, TauriCommand,
-    VariantData,
+    CommandArg, EnumRepresentation, EnumVariant, FieldPresence, RustEnum, RustStruct, RustType, StructField,
+    StructKind, TauriCommand, VariantData,
 };
 use tauri_ts_generator::parser::{parse_commands, parse_types};
 
@@ -39,7 +39,7 @@ fn test_generate_types_from_simple_fixture() {
         ctx.register_type(&e.name);
     }
 
-    let output = generate_types_file(&structs, &enums, &ctx);
+    let output = generate_types_file(&structs, &enums, &[], &ctx);
 
     // Check header
     assert!(output.contains("// This file was auto-generated by tauri-ts-generator"));
@@ -125,7 +125,7 @@ fn test_generate_complex_types() {
         ctx.register_type(&e.name);
     }
 
-    let output = generate_types_file(&structs, &enums, &ctx);
+    let output = generate_types_file(&structs, &enums, &[], &ctx);
 
     // Check generic types
     assert!(output.contains("export interface Wrapper<T>"));
@@ -156,8 +156,17 @@ fn test_generate_with_naming_prefix() {
             ty: RustType::Primitive("i32".to_string()),
             has_explicit_rename: false,
             use_optional: false,
+            presence: FieldPresence::Always,
+            flatten: false,
+            docs: Default::default(),
         }],
         source_file: PathBuf::from("test.rs"),
+        transparent: false,
+        kind: StructKind::Named,
+        docs: Default::default(),
+        event: None,
+        ts_name: None,
+        export: false,
     }];
 
     let mut ctx = GeneratorContext::new(NamingConfig {
@@ -168,7 +177,7 @@ fn test_generate_with_naming_prefix() {
     });
     ctx.register_type("User");
 
-    let output = generate_types_file(&structs, &[], &ctx);
+    let output = generate_types_file(&structs, &[], &[], &ctx);
 
     assert!(output.contains("export interface IUser"));
 }
@@ -180,6 +189,12 @@ fn test_generate_with_naming_suffix() {
         generics: vec![],
         fields: vec![],
         source_file: PathBuf::from("test.rs"),
+        transparent: false,
+        kind: StructKind::Named,
+        docs: Default::default(),
+        event: None,
+        ts_name: None,
+        export: false,
     }];
 
     let mut ctx = GeneratorContext::new(NamingConfig {
@@ -190,7 +205,7 @@ fn test_generate_with_naming_suffix() {
     });
     ctx.register_type("User");
 
-    let output = generate_types_file(&structs, &[], &ctx);
+    let output = generate_types_file(&structs, &[], &[], &ctx);
 
     assert!(output.contains("export interface UserDTO"));
 }
@@ -203,6 +218,11 @@ fn test_generate_command_with_function_prefix_suffix() {
         return_type: Some(RustType::Custom("User".to_string())),
         source_file: PathBuf::from("test.rs"),
         rename_all: None,
+        docs: Default::default(),
+        plugin: None,
+        raw_body: false,
+        ts_name: None,
+        ts_rename_all: None,
     }];
 
     let mut ctx = GeneratorContext::new(NamingConfig {
@@ -230,16 +250,27 @@ fn test_type_imports_in_commands_file() {
             return_type: Some(RustType::Custom("User".to_string())),
             source_file: PathBuf::from("test.rs"),
             rename_all: None,
+            docs: Default::default(),
+            plugin: None,
+            raw_body: false,
+            ts_name: None,
+            ts_rename_all: None,
         },
         TauriCommand {
             name: "create_item".to_string(),
             args: vec![CommandArg {
                 name: "item".to_string(),
                 ty: RustType::Custom("Item".to_string()),
+                docs: Default::default(),
             }],
             return_type: None,
             source_file: PathBuf::from("test.rs"),
             rename_all: None,
+            docs: Default::default(),
+            plugin: None,
+            raw_body: false,
+            ts_name: None,
+            ts_rename_all: None,
         },
     ];
 
@@ -262,7 +293,7 @@ fn test_type_imports_in_commands_file() {
 fn test_empty_types_generates_valid_file() {
     let ctx = GeneratorContext::new(NamingConfig::default());
 
-    let output = generate_types_file(&[], &[], &ctx);
+    let output = generate_types_file(&[], &[], &[], &ctx);
 
     assert!(output.contains("// This file was auto-generated by tauri-ts-generator"));
     // Should be a valid (though minimal) TypeScript file
@@ -287,35 +318,19 @@ fn test_empty_commands_generates_valid_file() {
 
 #[test]
 fn test_snake_case_fields_converted_to_camel_case() {
-    let structs = vec![RustStruct {
-        name: "User".to_string(),
-        generics: vec![],
-        fields: vec![
-            StructField {
-                name: "user_id".to_string(),
-                ty: RustType::Primitive("i32".to_string()),
-                has_explicit_rename: false,
-                use_optional: false,
-            },
-            StructField {
-                name: "first_name".to_string(),
-                ty: RustType::Primitive("String".to_string()),
-                has_explicit_rename: false,
-                use_optional: false,
-            },
-            StructField {
-                name: "last_login_at".to_string(),
-                ty: RustType::Primitive("DateTime".to_string()),
-                has_explicit_rename: false,
-                use_optional: false,
-            },
-        ],
-        source_file: PathBuf::from("test.rs"),
-    }];
+    let code = r#"
+        #[derive(Serialize)]
+        pub struct User {
+            pub user_id: i32,
+            pub first_name: String,
+            pub last_login_at: DateTime,
+        }
+    "#;
+    let (structs, _) = parse_types(code, &PathBuf::from("test.rs")).unwrap();
 
     let ctx = GeneratorContext::new(NamingConfig::default());
 
-    let output = generate_types_file(&structs, &[], &ctx);
+    let output = generate_types_file(&structs, &[], &[], &ctx);
 
     assert!(output.contains("userId: number"));
     assert!(output.contains("firstName: string"));
@@ -327,6 +342,27 @@ fn test_snake_case_fields_converted_to_camel_case() {
     assert!(!output.contains("last_login_at:"));
 }
 
+#[test]
+fn test_struct_rename_all_snake_case_keeps_field_names() {
+    let code = r#"
+        #[derive(Serialize)]
+        #[serde(rename_all = "snake_case")]
+        pub struct User {
+            pub user_id: i32,
+            #[serde(rename = "displayName")]
+            pub first_name: String,
+        }
+    "#;
+    let (structs, _) = parse_types(code, &PathBuf::from("test.rs")).unwrap();
+
+    let ctx = GeneratorContext::new(NamingConfig::default());
+    let output = generate_types_file(&structs, &[], &[], &ctx);
+
+    assert!(output.contains("user_id: number"));
+    assert!(output.contains("displayName: string"));
+    assert!(!output.contains("userId"));
+}
+
 #[test]
 fn test_complex_enum_discriminated_union() {
     let enums = vec![RustEnum {
@@ -341,34 +377,47 @@ fn test_complex_enum_discriminated_union() {
                         ty: RustType::Primitive("i32".to_string()),
                         has_explicit_rename: false,
                         use_optional: false,
+                        presence: FieldPresence::Always,
+                        flatten: false,
+                        docs: Default::default(),
                     },
                     StructField {
                         name: "y".to_string(),
                         ty: RustType::Primitive("i32".to_string()),
                         has_explicit_rename: false,
                         use_optional: false,
+                        presence: FieldPresence::Always,
+                        flatten: false,
+                        docs: Default::default(),
                     },
                 ]),
                 has_explicit_rename: false,
+                docs: Default::default(),
             },
             EnumVariant {
                 name: "KeyPress".to_string(),
                 data: VariantData::Tuple(vec![RustType::Primitive("String".to_string())]),
                 has_explicit_rename: false,
+                docs: Default::default(),
             },
             EnumVariant {
                 name: "Close".to_string(),
                 data: VariantData::Unit,
                 has_explicit_rename: false,
+                docs: Default::default(),
             },
         ],
         source_file: PathBuf::from("test.rs"),
         representation: EnumRepresentation::default(),
+        docs: Default::default(),
+        event: None,
+        ts_name: None,
+        export: false,
     }];
 
     let ctx = GeneratorContext::new(NamingConfig::default());
 
-    let output = generate_types_file(&[], &enums, &ctx);
+    let output = generate_types_file(&[], &enums, &[], &ctx);
 
     assert!(output.contains("export type Event"));
     // External representation
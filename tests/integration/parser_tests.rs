@@ -2,7 +2,7 @@
 
 use std::path::PathBuf;
 use tauri_ts_generator::parser::{parse_commands, parse_types};
-use tauri_ts_generator::models::RustType;
+use tauri_ts_generator::models::{RustType, StructKind};
 
 /// Get path to test fixtures
 fn fixture_path(name: &str) -> PathBuf {
@@ -39,7 +39,7 @@ fn test_parse_simple_commands_fixture() {
     let get_user = commands.iter().find(|c| c.name == "get_user").unwrap();
     assert_eq!(get_user.args.len(), 1);
     match &get_user.return_type {
-        Some(RustType::Result(inner)) => match inner.as_ref() {
+        Some(RustType::Result { ok: inner, .. }) => match inner.as_ref() {
             RustType::Custom(name) => assert_eq!(name, "User"),
             _ => panic!("Expected Custom type inside Result"),
         },
@@ -128,6 +128,7 @@ fn test_parse_complex_types_fixture() {
     assert_eq!(tuple_struct.fields.len(), 2);
     assert_eq!(tuple_struct.fields[0].name, "field0");
     assert_eq!(tuple_struct.fields[1].name, "field1");
+    assert_eq!(tuple_struct.kind, StructKind::Tuple);
 }
 
 #[test]
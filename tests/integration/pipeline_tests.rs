@@ -3,7 +3,7 @@
 use std::fs;
 use std::path::PathBuf;
 use tempfile::tempdir;
-use tauri_ts_generator::config::{Config, InputConfig, NamingConfig, OutputConfig};
+use tauri_ts_generator::config::{Config, ErrorHandling, InputConfig, NamingConfig, OutputConfig};
 use tauri_ts_generator::pipeline::Pipeline;
 
 /// Create a test config with temp directories
@@ -14,12 +14,20 @@ fn create_test_config(source_dir: PathBuf, output_dir: PathBuf) -> Config {
             exclude: vec!["tests".to_string(), "target".to_string()],
             use_cargo_expand: false,
             cargo_manifest: None,
+            only_registered_commands: false,
+            plugins: Default::default(),
+            injected_types: Vec::new(),
         },
         output: OutputConfig {
             types_file: output_dir.join("types.ts"),
             commands_file: output_dir.join("commands.ts"),
+            events_file: None,
+            max_array_tuple_len: 16,
+            error_handling: Default::default(),
+            export_all_serializable: false,
         },
         naming: NamingConfig::default(),
+        types: Default::default(),
     }
 }
 
@@ -228,10 +236,17 @@ pub fn get_user(id: i32) -> User {
             exclude: vec![],
             use_cargo_expand: false,
             cargo_manifest: None,
+            only_registered_commands: false,
+            plugins: Default::default(),
+            injected_types: Vec::new(),
         },
         output: OutputConfig {
             types_file: output_dir.join("types.ts"),
             commands_file: output_dir.join("commands.ts"),
+            events_file: None,
+            max_array_tuple_len: 16,
+            error_handling: Default::default(),
+            export_all_serializable: false,
         },
         naming: NamingConfig {
             type_prefix: "I".to_string(),
@@ -239,6 +254,7 @@ pub fn get_user(id: i32) -> User {
             function_prefix: "".to_string(),
             function_suffix: "Cmd".to_string(),
         },
+        types: Default::default(),
     };
 
     let pipeline = Pipeline::new(false);
@@ -842,3 +858,1773 @@ fn test_parse_expanded_real_progenitor_output() {
     assert!(names.contains(&"AuthResponse"), "Should find AuthResponse, got {:?}", names);
     assert!(names.contains(&"UserProfile"), "Should find UserProfile, got {:?}", names);
 }
+
+#[test]
+fn test_pipeline_preserves_generic_arguments() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("generated");
+
+    fs::create_dir_all(&src_dir).unwrap();
+
+    let code = r#"
+use serde::Serialize;
+use tauri::State;
+
+#[derive(Serialize)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub total: u32,
+}
+
+#[derive(Serialize)]
+pub struct User {
+    pub id: i32,
+}
+
+pub struct AppState;
+
+#[tauri::command]
+pub fn list_users(state: State<'_, AppState>, page: u32) -> Result<Page<User>, String> {
+    unimplemented!()
+}
+"#;
+    fs::write(src_dir.join("lib.rs"), code).unwrap();
+
+    let config = create_test_config(src_dir, output_dir.clone());
+    let pipeline = Pipeline::new(false);
+
+    let result = pipeline.run(&config);
+    assert!(result.is_ok(), "Pipeline should succeed: {:?}", result.err());
+
+    let types_content = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(types_content.contains("export interface Page<T>"));
+    assert!(types_content.contains("export interface User"));
+
+    let commands_content = fs::read_to_string(output_dir.join("commands.ts")).unwrap();
+    assert!(commands_content.contains("listUsers(page: number): Promise<Page<User>>"),
+            "Content:\n{}", commands_content);
+    assert!(commands_content.contains("import type { Page, User }"));
+}
+
+#[test]
+fn test_pipeline_skipped_fields() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("generated");
+
+    fs::create_dir_all(&src_dir).unwrap();
+
+    let code = r#"
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct Internal {
+    pub secret: String,
+}
+
+#[derive(Serialize)]
+pub struct Profile {
+    pub id: i32,
+    #[serde(skip)]
+    pub internal: Internal,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bio: Option<String>,
+}
+
+#[tauri::command]
+pub fn get_profile() -> Profile {
+    unimplemented!()
+}
+"#;
+    fs::write(src_dir.join("lib.rs"), code).unwrap();
+
+    let config = create_test_config(src_dir, output_dir.clone());
+    let pipeline = Pipeline::new(false);
+
+    let result = pipeline.run(&config);
+    assert!(result.is_ok(), "Pipeline should succeed: {:?}", result.err());
+
+    let types_content = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(types_content.contains("bio?: string;"), "Content:\n{}", types_content);
+    assert!(!types_content.contains("internal"));
+    // Types only reachable through skipped fields are not generated
+    assert!(!types_content.contains("export interface Internal"));
+}
+
+#[test]
+fn test_pipeline_container_default_only_optional_on_input() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("generated");
+
+    fs::create_dir_all(&src_dir).unwrap();
+
+    let code = r#"
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Default)]
+#[serde(default)]
+pub struct Stats {
+    pub count: u32,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default)]
+pub struct Filter {
+    pub limit: u32,
+}
+
+#[tauri::command]
+pub fn get_stats(filter: Filter) -> Stats {
+    unimplemented!()
+}
+"#;
+    fs::write(src_dir.join("lib.rs"), code).unwrap();
+
+    let config = create_test_config(src_dir, output_dir.clone());
+    let pipeline = Pipeline::new(false);
+
+    let result = pipeline.run(&config);
+    assert!(result.is_ok(), "Pipeline should succeed: {:?}", result.err());
+
+    let types_content = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    // serde always writes defaulted fields, so return-only types keep them required
+    assert!(types_content.contains("count: number;"), "Content:\n{}", types_content);
+    assert!(types_content.contains("limit?: number;"), "Content:\n{}", types_content);
+}
+
+#[test]
+fn test_pipeline_field_default_only_optional_on_input() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("generated");
+
+    fs::create_dir_all(&src_dir).unwrap();
+
+    let code = r#"
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub struct Page {
+    pub offset: u32,
+    #[serde(default)]
+    pub size: u32,
+}
+
+#[derive(Serialize)]
+pub struct Summary {
+    #[serde(default)]
+    pub total: u32,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+#[tauri::command]
+pub fn summarize(page: Page) -> Summary {
+    unimplemented!()
+}
+"#;
+    fs::write(src_dir.join("lib.rs"), code).unwrap();
+
+    let config = create_test_config(src_dir, output_dir.clone());
+    let pipeline = Pipeline::new(false);
+
+    let result = pipeline.run(&config);
+    assert!(result.is_ok(), "Pipeline should succeed: {:?}", result.err());
+
+    let types_content = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(types_content.contains("size?: number;"), "Content:\n{}", types_content);
+    assert!(types_content.contains("total: number;"), "Content:\n{}", types_content);
+    assert!(types_content.contains("warnings?: string[];"), "Content:\n{}", types_content);
+}
+
+#[test]
+fn test_pipeline_flattened_struct() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("generated");
+
+    fs::create_dir_all(&src_dir).unwrap();
+
+    let code = r#"
+use serde::Serialize;
+use std::collections::HashMap;
+
+#[derive(Serialize)]
+pub struct Metadata {
+    pub created_at: String,
+}
+
+#[derive(Serialize)]
+pub struct Document {
+    pub title: String,
+    #[serde(flatten)]
+    pub meta: Metadata,
+    #[serde(flatten)]
+    pub extra: HashMap<String, String>,
+}
+
+#[tauri::command]
+pub fn get_document() -> Document {
+    unimplemented!()
+}
+"#;
+    fs::write(src_dir.join("lib.rs"), code).unwrap();
+
+    let config = create_test_config(src_dir, output_dir.clone());
+    let pipeline = Pipeline::new(false);
+
+    let result = pipeline.run(&config);
+    assert!(result.is_ok(), "Pipeline should succeed: {:?}", result.err());
+
+    let types_content = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(types_content.contains("export interface Metadata"), "Content:\n{}", types_content);
+    // The map's values can't describe the other fields, so its index signature is loosened
+    assert!(types_content.contains("} & Metadata & Record<string, unknown>;"));
+    assert!(!types_content.contains("meta:"));
+}
+
+#[test]
+fn test_pipeline_type_aliases() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("generated");
+
+    fs::create_dir_all(&src_dir).unwrap();
+
+    let code = r#"
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct User {
+    pub id: i32,
+}
+
+#[derive(Serialize)]
+pub struct Paginated<T> {
+    pub items: Vec<T>,
+}
+
+pub type UserList = Vec<User>;
+pub type Page<T> = Paginated<T>;
+pub type NotUsed = Vec<String>;
+
+#[tauri::command]
+pub fn list_users() -> UserList {
+    vec![]
+}
+
+#[tauri::command]
+pub fn page_users() -> Page<User> {
+    unimplemented!()
+}
+"#;
+    fs::write(src_dir.join("lib.rs"), code).unwrap();
+
+    let config = create_test_config(src_dir, output_dir.clone());
+    let pipeline = Pipeline::new(false);
+
+    let result = pipeline.run(&config);
+    assert!(result.is_ok(), "Pipeline should succeed: {:?}", result.err());
+
+    let types_content = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(types_content.contains("export type UserList = User[];"), "Content:\n{}", types_content);
+    assert!(types_content.contains("export type Page<T> = Paginated<T>;"));
+    assert!(types_content.contains("export interface User"));
+    assert!(types_content.contains("export interface Paginated<T>"));
+    assert!(!types_content.contains("NotUsed"));
+
+    let commands_content = fs::read_to_string(output_dir.join("commands.ts")).unwrap();
+    assert!(commands_content.contains("Promise<UserList>"));
+    assert!(commands_content.contains("Promise<Page<User>>"));
+    assert!(commands_content.contains("import type { Page, User, UserList }"), "Content:\n{}", commands_content);
+}
+
+#[test]
+fn test_pipeline_unwraps_smart_pointers() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("generated");
+
+    fs::create_dir_all(&src_dir).unwrap();
+
+    let code = r#"
+use serde::Serialize;
+use std::sync::Arc;
+
+#[derive(Serialize)]
+pub struct Settings {
+    pub theme: String,
+}
+
+#[derive(Serialize)]
+pub enum Node {
+    Leaf(i32),
+    Branch(Box<Node>, Box<Node>),
+}
+
+#[tauri::command]
+pub fn get_settings() -> Arc<Settings> {
+    unimplemented!()
+}
+
+#[tauri::command]
+pub fn get_tree() -> Box<Node> {
+    unimplemented!()
+}
+"#;
+    fs::write(src_dir.join("lib.rs"), code).unwrap();
+
+    let config = create_test_config(src_dir, output_dir.clone());
+    let pipeline = Pipeline::new(false);
+
+    let result = pipeline.run(&config);
+    assert!(result.is_ok(), "Pipeline should succeed: {:?}", result.err());
+
+    let types_content = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(types_content.contains("{ Branch: [Node, Node] }"), "Content:\n{}", types_content);
+    assert!(!types_content.contains("Box"));
+
+    let commands_content = fs::read_to_string(output_dir.join("commands.ts")).unwrap();
+    assert!(commands_content.contains("Promise<Settings>"));
+    assert!(commands_content.contains("Promise<Node>"));
+    assert!(!commands_content.contains("Arc"));
+}
+
+#[test]
+fn test_pipeline_collections_and_arrays() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("generated");
+
+    fs::create_dir_all(&src_dir).unwrap();
+
+    let code = r#"
+use serde::Serialize;
+use std::collections::{BTreeSet, HashSet, VecDeque};
+use indexmap::IndexMap;
+
+#[derive(Serialize)]
+pub struct Tag {
+    pub label: String,
+}
+
+#[derive(Serialize)]
+pub struct Snapshot {
+    pub tags: HashSet<Tag>,
+    pub ids: BTreeSet<u64>,
+    pub queue: VecDeque<String>,
+    pub scores: IndexMap<String, f64>,
+    pub position: [f32; 3],
+    pub hash: [u8; 32],
+}
+
+#[tauri::command]
+pub fn snapshot() -> Snapshot {
+    unimplemented!()
+}
+"#;
+    fs::write(src_dir.join("lib.rs"), code).unwrap();
+
+    let mut config = create_test_config(src_dir, output_dir.clone());
+    config.output.max_array_tuple_len = 4;
+    let pipeline = Pipeline::new(false);
+
+    let result = pipeline.run(&config);
+    assert!(result.is_ok(), "Pipeline should succeed: {:?}", result.err());
+
+    let types_content = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(types_content.contains("tags: Tag[];"), "Content:\n{}", types_content);
+    assert!(types_content.contains("export interface Tag"));
+    assert!(types_content.contains("ids: number[];"));
+    assert!(types_content.contains("queue: string[];"));
+    assert!(types_content.contains("scores: Record<string, number>;"));
+    assert!(types_content.contains("position: [number, number, number];"));
+    assert!(types_content.contains("hash: number[];"));
+}
+
+#[test]
+fn test_pipeline_emits_jsdoc() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("generated");
+
+    fs::create_dir_all(&src_dir).unwrap();
+
+    let code = r#"
+use serde::Serialize;
+
+/// A registered user
+#[derive(Serialize)]
+pub struct User {
+    /// Display name
+    pub name: String,
+}
+
+/// Look up a user.
+///
+/// # Arguments
+///
+/// * `user_id` - The user's ID
+#[deprecated(note = "use find_user")]
+#[tauri::command]
+pub fn get_user(user_id: u32) -> User {
+    unimplemented!()
+}
+"#;
+    fs::write(src_dir.join("lib.rs"), code).unwrap();
+
+    let config = create_test_config(src_dir, output_dir.clone());
+    let pipeline = Pipeline::new(false);
+
+    let result = pipeline.run(&config);
+    assert!(result.is_ok(), "Pipeline should succeed: {:?}", result.err());
+
+    let types_content = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(
+        types_content.contains("/** A registered user */\nexport interface User {\n  /** Display name */\n  name: string;"),
+        "Content:\n{}",
+        types_content
+    );
+
+    let commands_content = fs::read_to_string(output_dir.join("commands.ts")).unwrap();
+    assert!(
+        commands_content.contains(
+            "/**\n * Look up a user.\n *\n * @param userId The user's ID\n * @deprecated use find_user\n */\nexport async function getUser("
+        ),
+        "Content:\n{}",
+        commands_content
+    );
+}
+
+#[test]
+fn test_pipeline_check_detects_stale_output() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("generated");
+
+    fs::create_dir_all(&src_dir).unwrap();
+
+    let code = r#"
+#[tauri::command]
+pub fn greet(name: String) -> String {
+    format!("Hello, {}!", name)
+}
+"#;
+    fs::write(src_dir.join("lib.rs"), code).unwrap();
+
+    let config = create_test_config(src_dir.clone(), output_dir.clone());
+    let pipeline = Pipeline::new(false);
+
+    // Nothing generated yet: both files are stale
+    let stale = pipeline.check(&config).unwrap();
+    assert_eq!(stale.len(), 2);
+    assert!(!output_dir.join("types.ts").exists(), "check must not write files");
+
+    pipeline.run(&config).unwrap();
+    assert!(pipeline.check(&config).unwrap().is_empty());
+
+    // Changing a command signature makes commands.ts stale
+    fs::write(
+        src_dir.join("lib.rs"),
+        code.replace("name: String", "name: String, excited: bool"),
+    )
+    .unwrap();
+    let stale = pipeline.check(&config).unwrap();
+    assert_eq!(stale, vec![output_dir.join("commands.ts")]);
+}
+
+#[test]
+fn test_pipeline_check_detects_stale_events_file() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("generated");
+
+    fs::create_dir_all(&src_dir).unwrap();
+
+    let code = r#"
+use serde::Serialize;
+
+#[derive(Serialize, TS)]
+#[ts(event = "saved")]
+pub struct Saved {
+    pub id: u32,
+}
+
+#[tauri::command]
+pub fn ping() {}
+"#;
+    fs::write(src_dir.join("lib.rs"), code).unwrap();
+
+    let config = create_test_config(src_dir.clone(), output_dir.clone());
+    let pipeline = Pipeline::new(false);
+
+    pipeline.run(&config).unwrap();
+    assert!(output_dir.join("events.ts").exists());
+
+    // Without event payloads, the events file left on disk is stale
+    fs::write(src_dir.join("lib.rs"), code.replace("#[ts(event = \"saved\")]\n", "")).unwrap();
+    let stale = pipeline.check(&config).unwrap();
+    assert!(stale.contains(&output_dir.join("events.ts")), "Stale: {:?}", stale);
+    assert!(output_dir.join("events.ts").exists(), "check must not remove files");
+
+    // Generating removes it
+    pipeline.run(&config).unwrap();
+    assert!(!output_dir.join("events.ts").exists());
+    assert!(pipeline.check(&config).unwrap().is_empty());
+}
+
+#[test]
+fn test_pipeline_check_creates_no_directories() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("generated");
+
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::write(src_dir.join("lib.rs"), "#[tauri::command]\npub fn ping() {}\n").unwrap();
+
+    let config_path = temp.path().join("tauri-codegen.toml");
+    fs::write(
+        &config_path,
+        format!(
+            "[input]\nsource_dir = \"{}\"\n\n[output]\ntypes_file = \"{}\"\ncommands_file = \"{}\"\n",
+            src_dir.display(),
+            output_dir.join("types.ts").display(),
+            output_dir.join("commands.ts").display()
+        ),
+    )
+    .unwrap();
+
+    let config = Config::load(&config_path).unwrap();
+    let stale = Pipeline::new(false).check(&config).unwrap();
+    assert_eq!(stale.len(), 2);
+    assert!(!output_dir.exists(), "check must not create output directories");
+}
+
+#[test]
+fn test_pipeline_leaves_unchanged_files_untouched() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("generated");
+
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::write(
+        src_dir.join("lib.rs"),
+        "#[tauri::command]\npub fn ping() -> String { String::new() }\n",
+    )
+    .unwrap();
+
+    let config = create_test_config(src_dir, output_dir.clone());
+    let pipeline = Pipeline::new(false);
+    pipeline.run(&config).unwrap();
+
+    // Backdate the outputs so any rewrite would be visible in the mtime
+    let past = std::time::SystemTime::now() - std::time::Duration::from_secs(3600);
+    for name in ["types.ts", "commands.ts"] {
+        let file = fs::File::options().write(true).open(output_dir.join(name)).unwrap();
+        file.set_modified(past).unwrap();
+    }
+
+    pipeline.run(&config).unwrap();
+
+    for name in ["types.ts", "commands.ts"] {
+        let modified = fs::metadata(output_dir.join(name)).unwrap().modified().unwrap();
+        assert_eq!(modified, past, "{} should not be rewritten", name);
+    }
+}
+
+#[test]
+fn test_pipeline_result_error_types() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("generated");
+
+    fs::create_dir_all(&src_dir).unwrap();
+
+    let code = r#"
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct User {
+    pub name: String,
+}
+
+#[derive(Serialize)]
+pub enum ErrorKind {
+    NotFound,
+    Forbidden,
+}
+
+#[derive(Serialize)]
+pub struct AppError {
+    pub kind: ErrorKind,
+    pub message: String,
+}
+
+#[tauri::command]
+pub fn get_user(id: u32) -> Result<User, AppError> {
+    unimplemented!()
+}
+
+#[tauri::command]
+pub fn delete_user(id: u32) -> Result<(), String> {
+    unimplemented!()
+}
+"#;
+    fs::write(src_dir.join("lib.rs"), code).unwrap();
+
+    let mut config = create_test_config(src_dir, output_dir.clone());
+    let pipeline = Pipeline::new(false);
+
+    let result = pipeline.run(&config);
+    assert!(result.is_ok(), "Pipeline should succeed: {:?}", result.err());
+
+    let types_content = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(types_content.contains("export interface AppError"), "Content:\n{}", types_content);
+    assert!(types_content.contains("export type ErrorKind"));
+
+    let commands_content = fs::read_to_string(output_dir.join("commands.ts")).unwrap();
+    assert!(commands_content.contains("import type { AppError, User } from \"./types\";"));
+    assert!(commands_content.contains("@throws {AppError}"), "Content:\n{}", commands_content);
+    assert!(commands_content.contains(
+        "export type CommandError = {\n  getUser: AppError;\n  deleteUser: string;\n};"
+    ));
+
+    config.output.error_handling = ErrorHandling::Result;
+    pipeline.run(&config).unwrap();
+
+    let commands_content = fs::read_to_string(output_dir.join("commands.ts")).unwrap();
+    assert!(
+        commands_content.contains("Promise<CommandResult<void, string>>"),
+        "Content:\n{}",
+        commands_content
+    );
+}
+
+#[test]
+fn test_pipeline_result_alias_error_type() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("generated");
+
+    fs::create_dir_all(&src_dir).unwrap();
+
+    fs::write(src_dir.join("lib.rs"), "mod commands;\nmod db;\nmod error;\n").unwrap();
+    fs::write(
+        src_dir.join("error.rs"),
+        r#"
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub enum Error {
+    NotFound,
+    Io(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+"#,
+    )
+    .unwrap();
+    // A second `Error` makes the name ambiguous outside the alias's module
+    fs::write(
+        src_dir.join("db.rs"),
+        r#"
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct Error {
+    pub code: u32,
+}
+"#,
+    )
+    .unwrap();
+    fs::write(
+        src_dir.join("commands.rs"),
+        r#"
+use serde::Serialize;
+use crate::error::Result;
+
+#[derive(Serialize)]
+pub struct User {
+    pub name: String,
+}
+
+#[tauri::command]
+pub fn get_user(id: u32) -> Result<User> {
+    unimplemented!()
+}
+"#,
+    )
+    .unwrap();
+
+    let config = create_test_config(src_dir, output_dir.clone());
+    let pipeline = Pipeline::new(false);
+
+    let result = pipeline.run(&config);
+    assert!(result.is_ok(), "Pipeline should succeed: {:?}", result.err());
+
+    let types_content = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(
+        types_content.contains("export type Error =\n  | \"NotFound\""),
+        "Content:\n{}",
+        types_content
+    );
+    assert!(!types_content.contains("code: number"));
+
+    let commands_content = fs::read_to_string(output_dir.join("commands.ts")).unwrap();
+    assert!(commands_content.contains("import type { Error, User } from \"./types\";"), "Content:\n{}", commands_content);
+    assert!(commands_content.contains("@throws {Error}"));
+    assert!(commands_content.contains("export type CommandError = {\n  getUser: Error;\n};"));
+    assert!(!commands_content.contains("unknown"));
+}
+
+#[test]
+fn test_pipeline_result_inside_data_types() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("generated");
+
+    fs::create_dir_all(&src_dir).unwrap();
+
+    let code = r#"
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct Item {
+    pub id: u32,
+}
+
+#[derive(Serialize)]
+pub struct ItemError {
+    pub reason: String,
+}
+
+#[derive(Serialize)]
+pub struct BatchReport {
+    pub results: Vec<Result<Item, ItemError>>,
+}
+
+#[tauri::command]
+pub fn run_batch() -> Result<BatchReport, String> {
+    unimplemented!()
+}
+"#;
+    fs::write(src_dir.join("lib.rs"), code).unwrap();
+
+    let config = create_test_config(src_dir, output_dir.clone());
+    let pipeline = Pipeline::new(false);
+
+    let result = pipeline.run(&config);
+    assert!(result.is_ok(), "Pipeline should succeed: {:?}", result.err());
+
+    let types_content = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(
+        types_content.contains("results: ({ Ok: Item } | { Err: ItemError })[];"),
+        "Content:\n{}",
+        types_content
+    );
+    assert!(types_content.contains("export interface ItemError"));
+
+    // The command's own Result is still unwrapped
+    let commands_content = fs::read_to_string(output_dir.join("commands.ts")).unwrap();
+    assert!(commands_content.contains("Promise<BatchReport>"), "Content:\n{}", commands_content);
+}
+
+#[test]
+fn test_pipeline_only_registered_commands() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("generated");
+
+    fs::create_dir_all(src_dir.join("commands")).unwrap();
+
+    fs::write(
+        src_dir.join("main.rs"),
+        r#"
+mod commands;
+
+#[tauri::command]
+fn greet(name: String) -> String {
+    name
+}
+
+fn main() {
+    tauri::Builder::default()
+        .invoke_handler(tauri::generate_handler![greet, commands::user::get_user, missing_command])
+        .run(tauri::generate_context!())
+        .expect("error while running tauri application");
+}
+"#,
+    )
+    .unwrap();
+    fs::write(src_dir.join("commands/mod.rs"), "pub mod user;\n").unwrap();
+    fs::write(
+        src_dir.join("commands/user.rs"),
+        r#"
+#[tauri::command]
+pub fn get_user(id: u32) -> String {
+    unimplemented!()
+}
+
+#[tauri::command]
+pub fn legacy_lookup(id: u32) -> String {
+    unimplemented!()
+}
+"#,
+    )
+    .unwrap();
+
+    let mut config = create_test_config(src_dir, output_dir.clone());
+    config.input.only_registered_commands = true;
+    let pipeline = Pipeline::new(false);
+
+    let result = pipeline.run(&config);
+    assert!(result.is_ok(), "Pipeline should succeed: {:?}", result.err());
+
+    let commands_content = fs::read_to_string(output_dir.join("commands.ts")).unwrap();
+    assert!(commands_content.contains("export async function greet("), "Content:\n{}", commands_content);
+    assert!(commands_content.contains("export async function getUser("));
+    assert!(!commands_content.contains("legacyLookup"));
+}
+
+#[test]
+fn test_pipeline_plugin_commands() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("generated");
+
+    fs::create_dir_all(src_dir.join("plugins/storage")).unwrap();
+    fs::create_dir_all(src_dir.join("plugins/audio")).unwrap();
+
+    fs::write(
+        src_dir.join("lib.rs"),
+        r#"
+mod plugins;
+
+#[tauri::command]
+fn greet(name: String) -> String {
+    name
+}
+"#,
+    )
+    .unwrap();
+    // Detected from the plugin builder
+    fs::write(
+        src_dir.join("plugins/storage/mod.rs"),
+        r#"
+mod commands;
+
+use tauri::plugin::{Builder, TauriPlugin};
+
+pub fn init<R: tauri::Runtime>() -> TauriPlugin<R> {
+    Builder::new("storage")
+        .invoke_handler(tauri::generate_handler![commands::get_item])
+        .build()
+}
+"#,
+    )
+    .unwrap();
+    fs::write(
+        src_dir.join("plugins/storage/commands.rs"),
+        r#"
+#[tauri::command]
+pub fn get_item(key: String) -> Option<String> {
+    unimplemented!()
+}
+"#,
+    )
+    .unwrap();
+    // Assigned through the config
+    fs::write(
+        src_dir.join("plugins/audio/mod.rs"),
+        r#"
+#[tauri::command]
+pub fn set_volume(level: f32) {}
+"#,
+    )
+    .unwrap();
+
+    let mut config = create_test_config(src_dir.clone(), output_dir.clone());
+    // Plugin directories are compared after normalizing `.` and `..`
+    config
+        .input
+        .plugins
+        .insert("audio".to_string(), src_dir.join("./plugins/storage/../audio"));
+    let pipeline = Pipeline::new(false);
+
+    let result = pipeline.run(&config);
+    assert!(result.is_ok(), "Pipeline should succeed: {:?}", result.err());
+
+    let commands_content = fs::read_to_string(output_dir.join("commands.ts")).unwrap();
+    assert!(commands_content.contains("export async function greet("), "Content:\n{}", commands_content);
+    assert!(commands_content.contains("export const storage = {"));
+    assert!(commands_content.contains("invoke<string | null>(\"plugin:storage|get_item\", { key })"));
+    assert!(commands_content.contains("export const audio = {"));
+    assert!(commands_content.contains("invoke<void>(\"plugin:audio|set_volume\", { level })"));
+}
+
+#[test]
+fn test_pipeline_event_payloads() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("generated");
+
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::create_dir_all(&output_dir).unwrap();
+
+    let code = r#"
+use serde::Serialize;
+use ts_macros::TS;
+
+#[derive(Serialize, TS)]
+#[ts(event = "download-progress")]
+pub struct DownloadProgress {
+    pub file: FileInfo,
+    pub percent: f32,
+}
+
+#[derive(Serialize)]
+pub struct FileInfo {
+    pub name: String,
+}
+
+#[tauri::command]
+pub fn start_download(url: String) {}
+"#;
+
+    fs::write(src_dir.join("lib.rs"), code).unwrap();
+
+    let config = create_test_config(src_dir, output_dir.clone());
+    let pipeline = Pipeline::new(false);
+
+    let result = pipeline.run(&config);
+    assert!(result.is_ok(), "Pipeline should succeed: {:?}", result.err());
+
+    // The payload and its nested types are generated although no command uses them
+    let types_content = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(types_content.contains("export interface DownloadProgress"), "Content:\n{}", types_content);
+    assert!(types_content.contains("export interface FileInfo"));
+
+    let events_content = fs::read_to_string(output_dir.join("events.ts")).unwrap();
+    assert!(events_content.contains("import type { DownloadProgress } from \"./types\";"));
+    assert!(events_content.contains(
+        "export function listenDownloadProgress(handler: EventCallback<DownloadProgress>): Promise<UnlistenFn>"
+    ));
+    assert!(events_content.contains("return emit(\"download-progress\", payload);"));
+}
+
+#[test]
+fn test_pipeline_without_events_writes_no_events_file() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("generated");
+
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::create_dir_all(&output_dir).unwrap();
+
+    fs::write(src_dir.join("lib.rs"), "#[tauri::command]\npub fn ping() {}\n").unwrap();
+
+    let config = create_test_config(src_dir, output_dir.clone());
+    let result = Pipeline::new(false).run(&config);
+    assert!(result.is_ok(), "Pipeline should succeed: {:?}", result.err());
+
+    assert!(!output_dir.join("events.ts").exists());
+}
+
+#[test]
+fn test_pipeline_channel_argument() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("generated");
+
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::create_dir_all(&output_dir).unwrap();
+
+    let code = r#"
+use serde::Serialize;
+use tauri::ipc::Channel;
+
+#[derive(Clone, Serialize)]
+#[serde(tag = "event", content = "data")]
+pub enum ProgressEvent {
+    Started { total: u64 },
+    Progress { chunk: Chunk },
+    Finished,
+}
+
+#[derive(Clone, Serialize)]
+pub struct Chunk {
+    pub len: usize,
+}
+
+#[tauri::command]
+pub fn download(url: String, on_event: Channel<ProgressEvent>) {}
+"#;
+
+    fs::write(src_dir.join("lib.rs"), code).unwrap();
+
+    let config = create_test_config(src_dir, output_dir.clone());
+    let pipeline = Pipeline::new(false);
+
+    let result = pipeline.run(&config);
+    assert!(result.is_ok(), "Pipeline should succeed: {:?}", result.err());
+
+    // The channel's message type and its nested types reach types.ts
+    let types_content = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(types_content.contains("export type ProgressEvent"), "Content:\n{}", types_content);
+    assert!(types_content.contains("export interface Chunk"));
+
+    let commands_content = fs::read_to_string(output_dir.join("commands.ts")).unwrap();
+    assert!(commands_content.contains("import { Channel, invoke } from \"@tauri-apps/api/core\";"));
+    assert!(commands_content.contains("import type { ProgressEvent } from \"./types\";"));
+    assert!(
+        commands_content.contains("download(url: string, onEvent: Channel<ProgressEvent>): Promise<void>"),
+        "Content:\n{}",
+        commands_content
+    );
+    assert!(commands_content.contains("invoke<void>(\"download\", { url, onEvent })"));
+}
+
+#[test]
+fn test_pipeline_project_type_named_channel() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("generated");
+
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::create_dir_all(&output_dir).unwrap();
+
+    let code = r#"
+mod stream;
+
+use stream::Channel;
+
+#[tauri::command]
+pub fn subscribe(channel: Channel<String>) {}
+"#;
+    fs::write(src_dir.join("lib.rs"), code).unwrap();
+    fs::write(
+        src_dir.join("stream.rs"),
+        r#"
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct Channel<T> {
+    pub topic: T,
+}
+"#,
+    )
+    .unwrap();
+
+    let config = create_test_config(src_dir, output_dir.clone());
+    let pipeline = Pipeline::new(false);
+
+    let result = pipeline.run(&config);
+    assert!(result.is_ok(), "Pipeline should succeed: {:?}", result.err());
+
+    let types_content = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(types_content.contains("export interface Channel<T>"), "Content:\n{}", types_content);
+
+    let commands_content = fs::read_to_string(output_dir.join("commands.ts")).unwrap();
+    assert!(commands_content.contains("import { invoke } from \"@tauri-apps/api/core\";"));
+    assert!(commands_content.contains("import type { Channel } from \"./types\";"));
+    assert!(
+        commands_content.contains("subscribe(channel: Channel<string>): Promise<void>"),
+        "Content:\n{}",
+        commands_content
+    );
+}
+
+#[test]
+fn test_pipeline_injected_types() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("generated");
+
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::create_dir_all(&output_dir).unwrap();
+
+    fs::write(
+        src_dir.join("lib.rs"),
+        r#"
+mod auth;
+
+use auth::Session;
+use tauri::ipc::{CommandScope, GlobalScope};
+
+#[tauri::command]
+pub fn upload(scope: CommandScope<Entry>, global: GlobalScope<Entry>, session: Session, name: String) {}
+"#,
+    )
+    .unwrap();
+    fs::write(
+        src_dir.join("auth.rs"),
+        r#"
+use serde::Deserialize;
+
+pub struct Session;
+
+#[derive(Deserialize)]
+pub struct Request {
+    pub body: String,
+}
+
+#[tauri::command]
+pub fn echo(request: Request) -> String {
+    unimplemented!()
+}
+"#,
+    )
+    .unwrap();
+
+    let mut config = create_test_config(src_dir, output_dir.clone());
+    config.input.injected_types = vec!["crate::auth::Session".to_string()];
+    let pipeline = Pipeline::new(false);
+
+    let result = pipeline.run(&config);
+    assert!(result.is_ok(), "Pipeline should succeed: {:?}", result.err());
+
+    let commands_content = fs::read_to_string(output_dir.join("commands.ts")).unwrap();
+    assert!(
+        commands_content.contains("export async function upload(name: string): Promise<void>"),
+        "Content:\n{}",
+        commands_content
+    );
+    // The project's own `Request` type is not mistaken for tauri::ipc::Request
+    assert!(commands_content.contains("export async function echo(request: Request): Promise<string>"));
+}
+
+#[test]
+fn test_pipeline_raw_binary_ipc() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("generated");
+
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::create_dir_all(&output_dir).unwrap();
+
+    let code = r#"
+use tauri::ipc::{InvokeBody, Request, Response};
+
+#[tauri::command]
+pub fn read_file(path: String) -> Response {
+    unimplemented!()
+}
+
+#[tauri::command]
+pub fn upload(request: Request<'_>) -> Result<(), String> {
+    unimplemented!()
+}
+
+#[tauri::command]
+pub fn thumbnail(request: Request<'_>) -> Result<tauri::ipc::Response, String> {
+    unimplemented!()
+}
+"#;
+
+    fs::write(src_dir.join("lib.rs"), code).unwrap();
+
+    let config = create_test_config(src_dir, output_dir.clone());
+    let pipeline = Pipeline::new(false);
+
+    let result = pipeline.run(&config);
+    assert!(result.is_ok(), "Pipeline should succeed: {:?}", result.err());
+
+    let commands_content = fs::read_to_string(output_dir.join("commands.ts")).unwrap();
+    assert!(
+        commands_content.contains("export async function readFile(path: string): Promise<ArrayBuffer>"),
+        "Content:\n{}",
+        commands_content
+    );
+    assert!(commands_content.contains("invoke<ArrayBuffer>(\"read_file\", { path })"));
+    assert!(commands_content.contains(
+        "export async function upload(body: ArrayBuffer | Uint8Array, headers?: Record<string, string>): Promise<void>"
+    ));
+    assert!(commands_content.contains("invoke<void>(\"upload\", body, headers ? { headers } : undefined)"));
+    assert!(commands_content.contains("invoke<ArrayBuffer>(\"thumbnail\", body, headers ? { headers } : undefined)"));
+}
+
+#[test]
+fn test_pipeline_known_types_by_import_path() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("generated");
+
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::create_dir_all(&output_dir).unwrap();
+
+    fs::write(
+        src_dir.join("lib.rs"),
+        r#"
+mod calendar;
+
+use serde::Serialize;
+use std::num::NonZeroU32;
+use std::time::{Duration, SystemTime};
+use calendar::Date;
+
+#[derive(Serialize)]
+pub struct Job {
+    pub timeout: Duration,
+    pub started: SystemTime,
+    pub retries: NonZeroU32,
+    pub attempts: std::num::Wrapping<u8>,
+    pub due: Date,
+    pub created: time::Date,
+    pub backoff: time::Duration,
+}
+
+#[tauri::command]
+pub fn get_job() -> Job {
+    unimplemented!()
+}
+"#,
+    )
+    .unwrap();
+    // A project type named like time::Date
+    fs::write(
+        src_dir.join("calendar.rs"),
+        r#"
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct Date {
+    pub day: u8,
+}
+"#,
+    )
+    .unwrap();
+
+    let config = create_test_config(src_dir, output_dir.clone());
+    let pipeline = Pipeline::new(false);
+
+    let result = pipeline.run(&config);
+    assert!(result.is_ok(), "Pipeline should succeed: {:?}", result.err());
+
+    let types_content = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(
+        types_content.contains("timeout: { secs: number; nanos: number };"),
+        "Content:\n{}",
+        types_content
+    );
+    assert!(types_content.contains("started: { secs_since_epoch: number; nanos_since_epoch: number };"));
+    assert!(types_content.contains("retries: number;"));
+    assert!(types_content.contains("attempts: number;"));
+    assert!(types_content.contains("due: Date;"));
+    assert!(types_content.contains("created: string;"));
+    assert!(types_content.contains("backoff: string;"));
+    assert!(types_content.contains("export interface Date {"));
+}
+
+#[test]
+fn test_pipeline_generic_project_type_named_like_known_type() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("generated");
+
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::create_dir_all(&output_dir).unwrap();
+
+    fs::write(
+        src_dir.join("lib.rs"),
+        r#"
+mod calendar;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use calendar::Date;
+
+#[derive(Serialize)]
+pub struct Job {
+    pub due: Date<u8>,
+    pub created: DateTime<Utc>,
+}
+
+#[tauri::command]
+pub fn get_job() -> Job {
+    unimplemented!()
+}
+"#,
+    )
+    .unwrap();
+    fs::write(
+        src_dir.join("calendar.rs"),
+        r#"
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct Date<T> {
+    pub day: T,
+}
+"#,
+    )
+    .unwrap();
+
+    let config = create_test_config(src_dir, output_dir.clone());
+    let pipeline = Pipeline::new(false);
+
+    let result = pipeline.run(&config);
+    assert!(result.is_ok(), "Pipeline should succeed: {:?}", result.err());
+
+    let types_content = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(types_content.contains("due: Date<number>;"), "Content:\n{}", types_content);
+    assert!(types_content.contains("created: string;"), "Content:\n{}", types_content);
+    assert!(types_content.contains("export interface Date<T> {"), "Content:\n{}", types_content);
+}
+
+#[test]
+fn test_pipeline_type_mappings() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("generated");
+
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::create_dir_all(&output_dir).unwrap();
+
+    fs::write(
+        src_dir.join("lib.rs"),
+        r#"
+mod money;
+
+use money::Money;
+use serde::Serialize;
+use ulid::Ulid;
+use uuid::Uuid;
+
+#[derive(Serialize)]
+pub struct Order {
+    pub id: Ulid,
+    pub total: Money,
+    pub items: Vec<Option<Money>>,
+    pub customer: Uuid,
+}
+
+#[tauri::command]
+pub fn get_order(id: Ulid) -> Order {
+    unimplemented!()
+}
+
+#[tauri::command]
+pub fn get_total(id: Ulid) -> Money {
+    unimplemented!()
+}
+"#,
+    )
+    .unwrap();
+    fs::write(
+        src_dir.join("money.rs"),
+        r#"
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct Money {
+    pub cents: i64,
+    pub currency: String,
+}
+"#,
+    )
+    .unwrap();
+
+    // Path imports are rewritten relative to each generated file
+    let config_path = temp.path().join("tauri-codegen.toml");
+    fs::write(
+        &config_path,
+        format!(
+            r#"
+[input]
+source_dir = "{}"
+
+[output]
+types_file = "{}"
+commands_file = "{}"
+
+[types]
+"ulid::Ulid" = "string"
+"crate::money::Money" = {{ ts = "MoneyDto", import = "{}" }}
+Uuid = "UuidString"
+"#,
+            src_dir.display(),
+            output_dir.join("types.ts").display(),
+            output_dir.join("api/commands.ts").display(),
+            temp.path().join("web/money").display()
+        ),
+    )
+    .unwrap();
+    let config = Config::load(&config_path).unwrap();
+    let pipeline = Pipeline::new(false);
+
+    let result = pipeline.run(&config);
+    assert!(result.is_ok(), "Pipeline should succeed: {:?}", result.err());
+
+    let types_content = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(
+        types_content.contains("import type { MoneyDto } from \"../web/money\";"),
+        "Content:\n{}",
+        types_content
+    );
+    assert!(types_content.contains("id: string;"));
+    assert!(types_content.contains("total: MoneyDto;"));
+    assert!(types_content.contains("items: (MoneyDto | null)[];"));
+    // Mappings take precedence over built-in types
+    assert!(types_content.contains("customer: UuidString;"));
+    // The mapped project type itself is not generated
+    assert!(!types_content.contains("export interface Money"));
+
+    let commands_content = fs::read_to_string(output_dir.join("api/commands.ts")).unwrap();
+    assert!(
+        commands_content.contains("import type { MoneyDto } from \"../../web/money\";"),
+        "Content:\n{}",
+        commands_content
+    );
+    assert!(commands_content.contains("export async function getOrder(id: string): Promise<Order>"));
+    assert!(commands_content.contains("export async function getTotal(id: string): Promise<MoneyDto>"));
+}
+
+#[test]
+fn test_pipeline_ts_type_overrides() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("generated");
+
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::create_dir_all(&output_dir).unwrap();
+
+    let code = r#"
+use serde::Serialize;
+use serde_with::{serde_as, DisplayFromStr};
+
+#[derive(Serialize)]
+pub struct Owner {
+    pub name: String,
+}
+
+#[derive(Serialize, TS)]
+#[ts(as = "String")]
+pub struct Version {
+    pub major: u32,
+    pub minor: u32,
+}
+
+#[serde_as]
+#[derive(Serialize, TS)]
+pub struct Account {
+    #[serde(with = "hex")]
+    #[ts(type = "`0x${string}`")]
+    pub key: Vec<u8>,
+    #[serde_as(as = "DisplayFromStr")]
+    #[ts(as = "String")]
+    pub balance: u128,
+    #[ts(as = "Vec<Owner>")]
+    pub owners: OwnerSet,
+    pub version: Version,
+}
+
+#[tauri::command]
+pub fn get_account() -> Account {
+    unimplemented!()
+}
+"#;
+    fs::write(src_dir.join("lib.rs"), code).unwrap();
+
+    let config = create_test_config(src_dir, output_dir.clone());
+    let pipeline = Pipeline::new(false);
+
+    let result = pipeline.run(&config);
+    assert!(result.is_ok(), "Pipeline should succeed: {:?}", result.err());
+
+    let types_content = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(
+        types_content.contains("key: `0x${string}`;"),
+        "Content:\n{}",
+        types_content
+    );
+    assert!(types_content.contains("balance: string;"));
+    assert!(types_content.contains("owners: Owner[];"));
+    // Types used through an `as` override are still generated
+    assert!(types_content.contains("export interface Owner {"));
+    assert!(types_content.contains("export type Version = string;"));
+}
+
+#[test]
+fn test_pipeline_ts_naming_attributes() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("generated");
+
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::create_dir_all(&output_dir).unwrap();
+
+    let code = r#"
+use serde::Serialize;
+use tauri_ts_generator::{ts, TS};
+
+#[derive(Serialize, TS)]
+#[ts(rename = "RecordEntry")]
+pub struct Record {
+    pub id: u32,
+    #[ts(skip)]
+    pub audit_log: Vec<String>,
+    pub tags: Vec<Tag>,
+}
+
+#[derive(Serialize, TS)]
+pub enum Tag {
+    Pinned,
+    #[ts(skip)]
+    Internal,
+}
+
+#[tauri::command]
+#[tauri_ts_generator::ts(rename = "fetchRecord")]
+pub fn get_record(record_id: u32) -> Result<Record, String> {
+    unimplemented!()
+}
+
+#[tauri::command]
+#[tauri_ts_generator::ts(skip)]
+pub fn debug_dump() -> String {
+    unimplemented!()
+}
+"#;
+    fs::write(src_dir.join("lib.rs"), code).unwrap();
+
+    let mut config = create_test_config(src_dir, output_dir.clone());
+    config.naming.type_prefix = "I".to_string();
+    let pipeline = Pipeline::new(false);
+
+    let result = pipeline.run(&config);
+    assert!(result.is_ok(), "Pipeline should succeed: {:?}", result.err());
+
+    let types_content = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    // The #[ts(rename)] name is used as is, other types keep the prefix
+    assert!(
+        types_content.contains("export interface RecordEntry {"),
+        "Content:\n{}",
+        types_content
+    );
+    assert!(types_content.contains("tags: ITag[];"));
+    assert!(!types_content.contains("auditLog"));
+    assert!(types_content.contains("export type ITag =\n  | \"Pinned\";"));
+
+    let commands_content = fs::read_to_string(output_dir.join("commands.ts")).unwrap();
+    assert!(
+        commands_content.contains("import type { RecordEntry } from \"./types\";"),
+        "Content:\n{}",
+        commands_content
+    );
+    assert!(commands_content.contains("export async function fetchRecord(recordId: number): Promise<RecordEntry>"));
+    assert!(!commands_content.contains("debugDump"));
+}
+
+#[test]
+fn test_pipeline_quotes_non_identifier_names() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("generated");
+
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::create_dir_all(&output_dir).unwrap();
+
+    let code = r#"
+use serde::Serialize;
+
+#[derive(Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct RequestHeaders {
+    pub content_type: String,
+    #[serde(rename = "x-api-key")]
+    pub api_key: String,
+    pub accept: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "event-kind")]
+pub enum Notice {
+    #[serde(rename = "rate-limited")]
+    RateLimited { retry_after: u32 },
+}
+
+#[tauri::command]
+pub fn send(headers: RequestHeaders) -> Notice {
+    unimplemented!()
+}
+"#;
+    fs::write(src_dir.join("lib.rs"), code).unwrap();
+
+    let config = create_test_config(src_dir, output_dir.clone());
+    let pipeline = Pipeline::new(false);
+
+    let result = pipeline.run(&config);
+    assert!(result.is_ok(), "Pipeline should succeed: {:?}", result.err());
+
+    let types_content = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(
+        types_content.contains("  \"content-type\": string;"),
+        "Content:\n{}",
+        types_content
+    );
+    assert!(types_content.contains("  \"x-api-key\": string;"));
+    assert!(!types_content.contains("\"accept\""));
+    assert!(
+        types_content.contains("{ \"event-kind\": \"rate-limited\""),
+        "Content:\n{}",
+        types_content
+    );
+}
+
+#[test]
+fn test_pipeline_exported_types() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("generated");
+
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::create_dir_all(&output_dir).unwrap();
+
+    let code = r#"
+use serde::Serialize;
+use tauri_ts_generator::TS;
+
+/// Stored in local storage by the frontend only
+#[derive(Serialize, TS)]
+#[ts(export)]
+pub struct Preferences {
+    pub theme: Theme,
+}
+
+#[derive(Serialize)]
+pub enum Theme {
+    Light,
+    Dark,
+}
+
+#[derive(Serialize)]
+pub struct Unused {
+    pub id: u32,
+}
+
+#[tauri::command]
+pub fn get_settings() -> serde_json::Value {
+    unimplemented!()
+}
+"#;
+    fs::write(src_dir.join("lib.rs"), code).unwrap();
+
+    let mut config = create_test_config(src_dir, output_dir.clone());
+    let pipeline = Pipeline::new(false);
+
+    let result = pipeline.run(&config);
+    assert!(result.is_ok(), "Pipeline should succeed: {:?}", result.err());
+
+    let types_content = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    // The exported type and the types it uses are generated, other unused types are not
+    assert!(
+        types_content.contains("export interface Preferences {"),
+        "Content:\n{}",
+        types_content
+    );
+    assert!(types_content.contains("export type Theme ="));
+    assert!(!types_content.contains("Unused"));
+
+    config.output.export_all_serializable = true;
+    let result = pipeline.run(&config);
+    assert!(result.is_ok(), "Pipeline should succeed: {:?}", result.err());
+
+    let types_content = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(
+        types_content.contains("export interface Unused {"),
+        "Content:\n{}",
+        types_content
+    );
+    assert!(types_content.contains("export interface Preferences {"));
+}
+
+#[test]
+fn test_pipeline_export_all_skips_duplicate_names() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("generated");
+
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::create_dir_all(&output_dir).unwrap();
+
+    fs::write(
+        src_dir.join("lib.rs"),
+        r#"
+mod admin;
+mod billing;
+mod shop;
+
+use serde::Serialize;
+use crate::shop::Item;
+
+#[derive(Serialize)]
+pub struct Note {
+    pub text: String,
+}
+
+#[tauri::command]
+pub fn get_item() -> Item {
+    unimplemented!()
+}
+"#,
+    )
+    .unwrap();
+    // `Item` is used by a command, so the other `Item` is left out
+    fs::write(
+        src_dir.join("shop.rs"),
+        r#"
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct Item {
+    pub sku: String,
+}
+"#,
+    )
+    .unwrap();
+    fs::write(
+        src_dir.join("admin.rs"),
+        r#"
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct Item {
+    pub id: u32,
+}
+
+#[derive(Serialize)]
+pub struct Invoice {
+    pub total: u64,
+}
+"#,
+    )
+    .unwrap();
+    // Neither unused `Invoice` can be picked over the other
+    fs::write(
+        src_dir.join("billing.rs"),
+        r#"
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct Invoice {
+    pub number: String,
+}
+"#,
+    )
+    .unwrap();
+
+    let mut config = create_test_config(src_dir, output_dir.clone());
+    config.output.export_all_serializable = true;
+    let pipeline = Pipeline::new(false);
+
+    let result = pipeline.run(&config);
+    assert!(result.is_ok(), "Pipeline should succeed: {:?}", result.err());
+
+    let types_content = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(types_content.contains("export interface Note {"), "Content:\n{}", types_content);
+    assert!(types_content.contains("sku: string;"), "Content:\n{}", types_content);
+    assert!(!types_content.contains("id: number;"), "Content:\n{}", types_content);
+    assert!(!types_content.contains("Invoice"), "Content:\n{}", types_content);
+}